@@ -98,6 +98,7 @@ fn make_sql_command() -> Result<SqlCommand<PostgresArg>, Infallible> {
     sql.push_value(false)?;
     expr::item_separator(&mut sql);
     sql.push_value("sqlstr")?;
+    expr::limit(&mut sql, 1_i64)?;
 
     Ok(sql)
 }
@@ -0,0 +1,57 @@
+use syn::{Attribute, LitStr};
+
+/// `#[sqlstr(...)]` attributes of the struct
+#[derive(Default)]
+pub struct ContainerAttrs {
+    pub table: Option<LitStr>,
+}
+
+/// `#[sqlstr(...)]` attributes of a field
+#[derive(Default)]
+pub struct FieldAttrs {
+    pub rename: Option<LitStr>,
+    /// The field is not a column
+    pub skip: bool,
+    /// The column has a default value, it is not inserted
+    pub default: bool,
+}
+
+pub fn container_attrs(attrs: &[Attribute]) -> syn::Result<ContainerAttrs> {
+    let mut container = ContainerAttrs::default();
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("sqlstr")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("table") {
+                container.table = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown sqlstr struct attribute"))
+            }
+        })?;
+    }
+
+    Ok(container)
+}
+
+pub fn field_attrs(attrs: &[Attribute]) -> syn::Result<FieldAttrs> {
+    let mut field = FieldAttrs::default();
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("sqlstr")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                field.rename = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("skip") {
+                field.skip = true;
+                Ok(())
+            } else if meta.path.is_ident("default") {
+                field.default = true;
+                Ok(())
+            } else {
+                Err(meta.error("unknown sqlstr field attribute"))
+            }
+        })?;
+    }
+
+    Ok(field)
+}
@@ -0,0 +1,69 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+use crate::table::Table;
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let table = Table::parse(&input)?;
+
+    let ident = &table.ident;
+    let (impl_generics, ty_generics, where_clause) = table.generics.split_for_impl();
+
+    let mut types = Vec::with_capacity(table.columns.len());
+    let mut assignments = Vec::with_capacity(table.columns.len());
+    for column in &table.columns {
+        let field = &column.field;
+        let name = &column.name;
+        let assignment = quote! {
+            if set {
+                sql.push_cmd(",");
+            } else {
+                ::sqlstr::expr::set_update(sql);
+                set = true;
+            }
+            ::sqlstr::expr::set_column(sql, #name);
+            sql.push_cmd(" ");
+            sql.push_value(value)?;
+        };
+
+        match column.option_inner() {
+            Some(inner) => {
+                types.push(inner);
+                assignments.push(quote! {
+                    if let Some(value) = &patch.#field {
+                        #assignment
+                    }
+                });
+            }
+            None => {
+                types.push(&column.ty);
+                assignments.push(quote! {
+                    let value = &patch.#field;
+                    #assignment
+                });
+            }
+        }
+    }
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Writes a `SET <column> = <value>, ...` clause with the columns
+            /// of the patch, `Option` fields are only set when they are
+            /// `Some`.
+            ///
+            /// Returns `false`, without writing the clause, when no column is
+            /// set.
+            #[allow(unused_mut, unused_assignments)]
+            pub fn update_set<Sql, Arg, E>(sql: &mut Sql, patch: &Self) -> Result<bool, E>
+            where
+                Sql: ::sqlstr::WriteSql<Arg>,
+                #(for<'__row> Arg: ::sqlstr::ArgumentBuffer<&'__row #types, Error = E>,)*
+            {
+                let mut set = false;
+                #(#assignments)*
+                Ok(set)
+            }
+        }
+    })
+}
@@ -0,0 +1,74 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Type};
+
+use crate::table::{Column, Table};
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let table = Table::parse(&input)?;
+    let columns: Vec<&Column> = table.columns.iter().filter(|col| !col.default).collect();
+    if columns.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "Insertable requires a column without a default value",
+        ));
+    }
+
+    let ident = &table.ident;
+    let (impl_generics, ty_generics, where_clause) = table.generics.split_for_impl();
+
+    let names: Vec<&str> = columns.iter().map(|col| col.name.as_str()).collect();
+    let column_list = format!(" ({})", names.join(", "));
+    let types: Vec<&Type> = columns.iter().map(|col| &col.ty).collect();
+    let first = &columns[0].field;
+    let rest = columns[1..].iter().map(|col| &col.field);
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Writes a `INSERT INTO <table> (<columns>) VALUES (...)` command
+            /// with a row, columns with a default value are not inserted.
+            pub fn insert_one<Sql, Arg, E>(sql: &mut Sql, row: &Self) -> Result<(), E>
+            where
+                Sql: ::sqlstr::WriteSql<Arg>,
+                #(for<'__row> Arg: ::sqlstr::ArgumentBuffer<&'__row #types, Error = E>,)*
+            {
+                Self::insert_many(sql, [row]).map(|_| ())
+            }
+
+            /// Writes a `INSERT INTO <table> (<columns>) VALUES (...), ...`
+            /// command with the rows, columns with a default value are not
+            /// inserted.
+            ///
+            /// Returns `false`, without writing the command, when there are no
+            /// rows.
+            pub fn insert_many<'__rows, Sql, Arg, E, I>(sql: &mut Sql, rows: I) -> Result<bool, E>
+            where
+                Self: '__rows,
+                Sql: ::sqlstr::WriteSql<Arg>,
+                I: IntoIterator<Item = &'__rows Self>,
+                #(for<'__row> Arg: ::sqlstr::ArgumentBuffer<&'__row #types, Error = E>,)*
+            {
+                let mut rows = rows.into_iter().peekable();
+                if rows.peek().is_none() {
+                    return Ok(false);
+                }
+
+                ::sqlstr::expr::insert_into(sql, <Self as ::sqlstr::schema::Table>::TABLE);
+                sql.push_cmd(#column_list);
+                ::sqlstr::expr::values(sql);
+
+                for (i, row) in rows.enumerate() {
+                    sql.push_cmd(if i == 0 { " (" } else { ", (" });
+                    sql.push_value(&row.#first)?;
+                    #(
+                        sql.push_cmd(", ");
+                        sql.push_value(&row.#rest)?;
+                    )*
+                    sql.push_cmd(")");
+                }
+
+                Ok(true)
+            }
+        }
+    })
+}
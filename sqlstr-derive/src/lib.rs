@@ -0,0 +1,196 @@
+//! Derive macros and the `sql!` macro of [sqlstr](https://docs.rs/sqlstr),
+//! enabled by its `derive` feature.
+
+mod attr;
+mod changeset;
+mod insert;
+mod sql;
+mod table;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, LitStr};
+
+/// Derives `sqlstr::schema::Table` for a struct with named fields.
+///
+/// A `<Struct>Column` enum is generated with a variant for each field, in
+/// pascal case, with the `TABLE` name and the column names from `as_str`.
+///
+/// # Attributes
+///
+/// - `#[sqlstr(table = "...")]` on the struct: name of the table, defaults
+///   to the struct name in snake case.
+/// - `#[sqlstr(rename = "...")]` on a field: name of the column, defaults to
+///   the field name.
+/// - `#[sqlstr(skip)]` on a field: the field is not a column.
+///
+/// # Example
+///
+/// ```
+/// use sqlstr::schema::Table;
+///
+/// #[derive(Table)]
+/// #[sqlstr(table = "user")]
+/// struct User {
+///     id: i64,
+///     #[sqlstr(rename = "created_at")]
+///     created: u64,
+///     name: String,
+/// }
+///
+/// assert_eq!(User::TABLE, "user");
+/// assert_eq!(User::COLUMNS, "id, created_at, name");
+/// assert_eq!(User::column_list().expr().as_str(), User::COLUMNS);
+/// assert_eq!(UserColumn::Created.as_str(), "created_at");
+/// ```
+#[proc_macro_derive(Table, attributes(sqlstr))]
+pub fn derive_table(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    table::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `insert_one` and `insert_many` functions writing a
+/// `INSERT INTO <table> (<columns>) VALUES (...)` command, for a struct which
+/// derives [Table](macro@Table).
+///
+/// Each field is pushed by reference as an argument, in the order of the
+/// columns. The `#[sqlstr(default)]` fields are columns with a default value
+/// in the database, they are not inserted.
+///
+/// # Example
+///
+/// ```
+/// use sqlstr::{schema::{Insertable, Table}, SqlCommand, Void};
+/// # use core::convert::Infallible;
+///
+/// #[derive(Table, Insertable)]
+/// struct User {
+///     #[sqlstr(default)]
+///     id: i64,
+///     name: String,
+///     email: String,
+/// }
+///
+/// # fn main() -> Result<(), Infallible> {
+/// let user = User { id: 0, name: "foo".into(), email: "foo@bar.com".into() };
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// User::insert_one(&mut sql, &user)?;
+///
+/// assert_eq!(sql.as_command(), "INSERT INTO user (name, email) VALUES ($1, $2)");
+/// # Ok(())
+/// # }
+/// ```
+#[proc_macro_derive(Insertable, attributes(sqlstr))]
+pub fn derive_insertable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    insert::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives an `update_set` function writing the `SET` clause of a `UPDATE`
+/// command from a patch, for a struct which derives [Table](macro@Table).
+///
+/// Each field is pushed by reference as an argument, `Option` fields are only
+/// set when they are `Some`, with the inner value.
+///
+/// # Example
+///
+/// ```
+/// use sqlstr::{schema::{AsChangeset, Table}, SqlCommand, Void};
+/// use sqlstr::expr::{update_table, filter_where};
+/// # use core::convert::Infallible;
+///
+/// #[derive(Table, AsChangeset)]
+/// #[sqlstr(table = "user")]
+/// struct UserPatch {
+///     name: Option<String>,
+///     email: Option<String>,
+/// }
+///
+/// # fn main() -> Result<(), Infallible> {
+/// let patch = UserPatch { name: None, email: Some("foo@bar.com".into()) };
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// update_table(&mut sql, "user");
+/// UserPatch::update_set(&mut sql, &patch)?;
+/// filter_where(&mut sql);
+/// sql.push_cmd(" id = 1");
+///
+/// assert_eq!(sql.as_command(), "UPDATE user SET email = $1 WHERE id = 1");
+/// # Ok(())
+/// # }
+/// ```
+#[proc_macro_derive(AsChangeset, attributes(sqlstr))]
+pub fn derive_as_changeset(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    changeset::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Writes a command from a sql template, with the values of the `{expr}`
+/// placeholders pushed as arguments.
+///
+/// The macro returns a `Result<SqlCommand<Arg>, Arg::Error>`, the argument
+/// buffer is inferred from the use of the command.
+///
+/// - `{expr}`: a value, written as a placeholder `$N` and pushed with
+///   `push_value`.
+/// - `{expr:raw}`: a trusted sql, like a identifier, written as is. The
+///   expression must implement `AsRef<str>`.
+/// - `{{` and `}}`: literal braces, a block expression is written with a
+///   space: `{ { ... } }`.
+///
+/// The quotes, comments and parentheses of the sql are checked at compile
+/// time, and a value can not be written inside of quotes or comments. The sql
+/// is scanned with the Postgres rules: `--` and nested `/* */` comments,
+/// `E'...'` strings with backslash escapes and `$tag$` quoted strings.
+///
+/// # Example
+///
+/// ```
+/// use sqlstr::{sql, SqlCommand, Void};
+/// # use core::convert::Infallible;
+///
+/// # fn main() -> Result<(), Infallible> {
+/// let email = "foo@bar.com";
+/// let min_age = 18;
+/// let table = "user";
+///
+/// let sql: SqlCommand<Void> = sql!(
+///     "SELECT id FROM {table:raw} WHERE email = {email} AND age > {min_age + 1}"
+/// )?;
+///
+/// assert_eq!(
+///     sql.as_command(),
+///     "SELECT id FROM user WHERE email = $1 AND age > $2"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Unbalanced sql does not compile:
+///
+/// ```compile_fail
+/// use sqlstr::{sql, SqlCommand, Void};
+///
+/// let id = 1;
+/// let sql: Result<SqlCommand<Void>, _> = sql!("SELECT count(id FROM user WHERE id = {id}");
+/// ```
+///
+/// Neither do values inside of quotes:
+///
+/// ```compile_fail
+/// use sqlstr::{sql, SqlCommand, Void};
+///
+/// let name = "foo";
+/// let sql: Result<SqlCommand<Void>, _> = sql!("SELECT id FROM user WHERE name = '{name}'");
+/// ```
+#[proc_macro]
+pub fn sql(input: TokenStream) -> TokenStream {
+    let template = parse_macro_input!(input as LitStr);
+    sql::expand(template)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
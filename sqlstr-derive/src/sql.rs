@@ -0,0 +1,322 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+/// Piece of the `sql!` template
+enum Segment {
+    /// Literal sql
+    Sql(String),
+    /// `{expr}`, pushed as an argument
+    Value(TokenStream),
+    /// `{expr:raw}`, trusted sql written as is
+    Raw(TokenStream),
+}
+
+pub fn expand(template: LitStr) -> syn::Result<TokenStream> {
+    let segments = parse_segments(&template)?;
+    validate(&template, &segments)?;
+
+    let writes = segments.iter().map(|segment| match segment {
+        Segment::Sql(sql) => quote! {
+            __sql.push_cmd(#sql);
+        },
+        Segment::Value(expr) => quote! {
+            if let ::core::result::Result::Err(err) = __sql.push_value(#expr) {
+                break '__sqlstr ::core::result::Result::Err(err);
+            }
+        },
+        Segment::Raw(expr) => quote! {
+            __sql.push_cmd(::core::convert::AsRef::<str>::as_ref(&#expr));
+        },
+    });
+
+    Ok(quote! {
+        {
+            let mut __sql = ::sqlstr::SqlCommand::default();
+            '__sqlstr: {
+                #(#writes)*
+                ::core::result::Result::Ok(__sql)
+            }
+        }
+    })
+}
+
+/// Splits the template in sql and `{...}` placeholders, `{{` and `}}` are
+/// escaped braces.
+fn parse_segments(template: &LitStr) -> syn::Result<Vec<Segment>> {
+    let value = template.value();
+    let mut segments = Vec::new();
+    let mut sql = String::new();
+    let mut chars = value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                sql.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                sql.push('}');
+            }
+            '}' => {
+                return Err(syn::Error::new_spanned(
+                    template,
+                    "unmatched `}` in sql, use `}}` for a literal brace",
+                ))
+            }
+            '{' => {
+                let mut placeholder = String::new();
+                let mut depth = 0_usize;
+                loop {
+                    match chars.next() {
+                        Some('}') if depth == 0 => break,
+                        Some(c) => {
+                            match c {
+                                '{' => depth += 1,
+                                '}' => depth -= 1,
+                                _ => {}
+                            }
+                            placeholder.push(c);
+                        }
+                        None => {
+                            return Err(syn::Error::new_spanned(
+                                template,
+                                "unclosed `{` in sql, use `{{` for a literal brace",
+                            ))
+                        }
+                    }
+                }
+
+                if !sql.is_empty() {
+                    segments.push(Segment::Sql(core::mem::take(&mut sql)));
+                }
+                segments.push(parse_placeholder(template, &placeholder)?);
+            }
+            c => sql.push(c),
+        }
+    }
+
+    if !sql.is_empty() {
+        segments.push(Segment::Sql(sql));
+    }
+    Ok(segments)
+}
+
+fn parse_placeholder(template: &LitStr, placeholder: &str) -> syn::Result<Segment> {
+    let (expr, raw) = match placeholder.strip_suffix(":raw") {
+        Some(expr) if !expr.ends_with(':') => (expr, true),
+        _ => (placeholder, false),
+    };
+
+    if expr.trim().is_empty() {
+        return Err(syn::Error::new_spanned(
+            template,
+            "empty `{}` in sql, use `{{}}` for literal braces",
+        ));
+    }
+    let expr: TokenStream = expr.parse().map_err(|err| {
+        syn::Error::new_spanned(
+            template,
+            format!("invalid expression `{{{placeholder}}}` in sql: {err}"),
+        )
+    })?;
+    let expr = quote!((#expr));
+
+    Ok(if raw {
+        Segment::Raw(expr)
+    } else {
+        Segment::Value(expr)
+    })
+}
+
+/// Scanning state of the sql, the scan follows the lexical rules of
+/// `sqlstr`'s placeholder scanning for Postgres.
+enum Scan {
+    Sql,
+    /// Quoted string or identifier, with backslash escapes for `E'...'`
+    Quote(char, bool),
+    LineComment,
+    /// Nested `/* */` comment, with the depth of the inner comments
+    BlockComment(usize),
+    /// `$tag$` quoted string
+    DollarQuote(Vec<char>),
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+/// Checks the quotes, comments and parentheses of the sql are balanced and
+/// the values are not inside of quotes or comments.
+fn validate(template: &LitStr, segments: &[Segment]) -> syn::Result<()> {
+    let error = |message: &str| Err(syn::Error::new_spanned(template, message));
+
+    let mut depth = 0_usize;
+    let mut scan = Scan::Sql;
+    // last two chars before the current segment, a value is a `$N` placeholder
+    let mut before = [None, None];
+    for segment in segments {
+        let sql = match segment {
+            Segment::Sql(sql) => sql,
+            Segment::Value(_) => match scan {
+                Scan::Sql => {
+                    before = [Some('$'), Some('0')];
+                    continue;
+                }
+                Scan::LineComment | Scan::BlockComment(_) => return error(
+                    "a value can not be inside of a comment in sql, use `{{` for a literal brace",
+                ),
+                Scan::Quote(..) | Scan::DollarQuote(_) => {
+                    return error(
+                        "a value can not be inside of quotes in sql, it is a bound argument",
+                    )
+                }
+            },
+            Segment::Raw(_) => {
+                before = [None, None];
+                continue;
+            }
+        };
+
+        let chars: Vec<char> = sql.chars().collect();
+        let prev = |i: usize, n: usize| {
+            if i >= n {
+                Some(chars[i - n])
+            } else {
+                before[2 - (n - i)]
+            }
+        };
+
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let next = chars.get(i + 1).copied();
+            match &mut scan {
+                Scan::Quote(open, escapes) => {
+                    if *escapes && c == '\\' {
+                        i += 1;
+                    } else if c == *open {
+                        // a doubled quote is an escaped quote, closed and opened again
+                        scan = Scan::Sql;
+                    }
+                }
+                Scan::LineComment => {
+                    if c == '\n' {
+                        scan = Scan::Sql;
+                    }
+                }
+                Scan::BlockComment(comment_depth) => match (c, next) {
+                    ('*', Some('/')) => {
+                        i += 1;
+                        match comment_depth.checked_sub(1) {
+                            Some(outer) => *comment_depth = outer,
+                            None => scan = Scan::Sql,
+                        }
+                    }
+                    ('/', Some('*')) => {
+                        i += 1;
+                        *comment_depth += 1;
+                    }
+                    _ => {}
+                },
+                Scan::DollarQuote(tag) => {
+                    if chars[i..].starts_with(tag) {
+                        i += tag.len() - 1;
+                        scan = Scan::Sql;
+                    }
+                }
+                Scan::Sql => match c {
+                    '\'' => {
+                        let escape_string = prev(i, 1)
+                            .is_some_and(|e| e.eq_ignore_ascii_case(&'e'))
+                            && !prev(i, 2).is_some_and(is_identifier_char);
+                        scan = Scan::Quote(c, escape_string);
+                    }
+                    '"' => scan = Scan::Quote(c, false),
+                    '-' if next == Some('-') => {
+                        i += 1;
+                        scan = Scan::LineComment;
+                    }
+                    '/' if next == Some('*') => {
+                        i += 1;
+                        scan = Scan::BlockComment(0);
+                    }
+                    '$' if !prev(i, 1).is_some_and(is_identifier_char)
+                        && !next.is_some_and(|next| next.is_ascii_digit()) =>
+                    {
+                        let mut end = i + 1;
+                        while end < chars.len()
+                            && is_identifier_char(chars[end])
+                            && chars[end] != '$'
+                        {
+                            end += 1;
+                        }
+                        if end < chars.len() && chars[end] == '$' {
+                            scan = Scan::DollarQuote(chars[i..=end].to_vec());
+                            i = end;
+                        }
+                    }
+                    '(' => depth += 1,
+                    ')' if depth == 0 => return error("unbalanced `)` in sql"),
+                    ')' => depth -= 1,
+                    _ => {}
+                },
+            }
+            i += 1;
+        }
+
+        before = [prev(chars.len(), 2), prev(chars.len(), 1)];
+    }
+
+    match scan {
+        Scan::Quote(..) | Scan::DollarQuote(_) => return error("unclosed quote in sql"),
+        Scan::BlockComment(_) => return error("unclosed comment in sql"),
+        Scan::Sql | Scan::LineComment => {}
+    }
+    if depth > 0 {
+        return error("unclosed `(` in sql");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn check(sql: &str) -> syn::Result<()> {
+        let template = LitStr::new(sql, proc_macro2::Span::call_site());
+        validate(&template, &parse_segments(&template)?)
+    }
+
+    #[test]
+    fn validate_sql() {
+        assert!(check("SELECT count(id) FROM user WHERE name = 'it''s' AND id = {id}").is_ok());
+        assert!(check("SELECT \"{column:raw}\" FROM {{table}}").is_ok());
+        assert!(check("SELECT Foo {{ x: 1 }} FROM t").is_ok());
+        assert!(check("SELECT (id FROM user").is_err());
+        assert!(check("SELECT id) FROM user").is_err());
+        assert!(check("SELECT 'foo FROM user").is_err());
+        assert!(check("SELECT '{name}' FROM user").is_err());
+        assert!(check("SELECT {name FROM user").is_err());
+        assert!(check("SELECT name} FROM user").is_err());
+        assert!(check("SELECT { } FROM user").is_err());
+        assert!(check("SELECT {(} FROM user").is_err());
+    }
+
+    #[test]
+    fn validate_comments_and_strings() {
+        assert!(check("SELECT id -- don't\nFROM t WHERE id = {id}").is_ok());
+        assert!(check("SELECT id /* it's /* (nested */ */ FROM t").is_ok());
+        assert!(check("SELECT E'it\\'s (' FROM t WHERE id = {id}").is_ok());
+        assert!(check("SELECT $$it's$$, $tag$ $$ ' $tag$ FROM t WHERE id = {id}").is_ok());
+        assert!(check("SELECT a$b, $1 FROM t").is_ok());
+        assert!(check("SELECT {id} -- end").is_ok());
+        assert!(check("SELECT id -- {id}\nFROM t").is_err());
+        assert!(check("SELECT id /* {id} */ FROM t").is_err());
+        assert!(check("SELECT $$ {id} $$").is_err());
+        assert!(check("SELECT id /* FROM t").is_err());
+        assert!(check("SELECT $tag$ FROM t").is_err());
+        assert!(check("SELECT 'it\\'s' FROM t").is_err());
+    }
+}
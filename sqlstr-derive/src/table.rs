@@ -0,0 +1,210 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    Data, DeriveInput, Fields, GenericArgument, Generics, Ident, PathArguments, Type, Visibility,
+};
+
+use crate::attr::{container_attrs, field_attrs};
+
+/// Column of a table, from a named field
+pub struct Column {
+    pub field: Ident,
+    pub ty: Type,
+    pub name: String,
+    pub variant: Ident,
+    pub default: bool,
+}
+
+impl Column {
+    /// Inner type of a `Option<T>` field
+    pub fn option_inner(&self) -> Option<&Type> {
+        let Type::Path(path) = &self.ty else {
+            return None;
+        };
+        let segment = path.path.segments.last()?;
+        if segment.ident != "Option" {
+            return None;
+        }
+        match &segment.arguments {
+            PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+                match args.args.first()? {
+                    GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Table of a struct with named fields
+pub struct Table {
+    pub ident: Ident,
+    pub vis: Visibility,
+    pub generics: Generics,
+    pub name: String,
+    pub columns: Vec<Column>,
+}
+
+impl Table {
+    pub fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let fields = match &input.data {
+            Data::Struct(data) => match &data.fields {
+                Fields::Named(fields) => &fields.named,
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        &input.ident,
+                        "sqlstr derive requires a struct with named fields",
+                    ))
+                }
+            },
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "sqlstr derive requires a struct",
+                ))
+            }
+        };
+
+        let container = container_attrs(&input.attrs)?;
+        let name = container
+            .table
+            .map(|table| table.value())
+            .unwrap_or_else(|| snake_case(&input.ident.to_string()));
+
+        let mut columns = Vec::with_capacity(fields.len());
+        for field in fields {
+            let ident = field.ident.clone().expect("named field");
+            let attrs = field_attrs(&field.attrs)?;
+            if attrs.skip {
+                continue;
+            }
+
+            let field_name = ident.to_string();
+            let field_name = field_name.trim_start_matches("r#");
+            let name = attrs
+                .rename
+                .as_ref()
+                .map(|rename| rename.value())
+                .unwrap_or_else(|| field_name.to_owned());
+
+            columns.push(Column {
+                variant: Ident::new(&pascal_case(field_name), Span::call_site()),
+                field: ident,
+                ty: field.ty.clone(),
+                name,
+                default: attrs.default,
+            });
+        }
+
+        Ok(Self {
+            ident: input.ident.clone(),
+            vis: input.vis.clone(),
+            generics: input.generics.clone(),
+            name,
+            columns,
+        })
+    }
+
+    /// Name of the generated column enum: `<Struct>Column`
+    pub fn column_enum(&self) -> Ident {
+        format_ident!("{}Column", self.ident)
+    }
+}
+
+/// Words start at a lowercase or digit to uppercase boundary, and at the last
+/// capital of an uppercase run followed by a lowercase letter: `HTTPLog` is
+/// `http_log`.
+fn snake_case(ident: &str) -> String {
+    let chars: Vec<char> = ident.chars().collect();
+    let mut snake = String::with_capacity(ident.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            let boundary =
+                prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next_lower);
+            if boundary {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
+
+fn pascal_case(ident: &str) -> String {
+    let mut pascal = String::with_capacity(ident.len());
+    let mut upper = true;
+    for c in ident.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            pascal.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            pascal.push(c);
+        }
+    }
+    pascal
+}
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let table = Table::parse(&input)?;
+
+    let ident = &table.ident;
+    let vis = &table.vis;
+    let column_enum = table.column_enum();
+    let (impl_generics, ty_generics, where_clause) = table.generics.split_for_impl();
+
+    let table_name = &table.name;
+    let names: Vec<&str> = table.columns.iter().map(|col| col.name.as_str()).collect();
+    let variants: Vec<&Ident> = table.columns.iter().map(|col| &col.variant).collect();
+    let columns = names.join(", ");
+    let count = names.len();
+    let enum_doc = format!("Columns of the [`{ident}`] table");
+
+    Ok(quote! {
+        #[doc = #enum_doc]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        #vis enum #column_enum {
+            #(#variants,)*
+        }
+
+        impl #column_enum {
+            pub const TABLE: &'static str = #table_name;
+            pub const ALL: [Self; #count] = [#(Self::#variants,)*];
+
+            pub const fn as_str(&self) -> &'static str {
+                match *self {
+                    #(Self::#variants => #names,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::sqlstr::schema::Table for #ident #ty_generics #where_clause {
+            const TABLE: &'static str = #table_name;
+            const COLUMNS: &'static str = #columns;
+            const COLUMN_NAMES: &'static [&'static str] = &[#(#names,)*];
+
+            type Column = #column_enum;
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn case_conversion() {
+        assert_eq!(snake_case("User"), "user");
+        assert_eq!(snake_case("OrderItem"), "order_item");
+        assert_eq!(snake_case("HTTPLog"), "http_log");
+        assert_eq!(snake_case("UserID"), "user_id");
+        assert_eq!(snake_case("Oauth2Token"), "oauth2_token");
+        assert_eq!(pascal_case("id"), "Id");
+        assert_eq!(pascal_case("created_at"), "CreatedAt");
+        assert_eq!(pascal_case("_hidden"), "Hidden");
+    }
+}
@@ -0,0 +1,24 @@
+use core::fmt::{self, Debug, Write};
+
+use sqlstr::ArgumentBuffer;
+
+/// Argument buffer writing the arguments with their `Debug` format: `a;b;`.
+#[derive(Default)]
+pub struct DebugArgs(pub String, pub u32);
+
+impl<T> ArgumentBuffer<T> for DebugArgs
+where
+    T: Debug,
+{
+    type Error = fmt::Error;
+
+    fn push(&mut self, value: T) -> Result<(), Self::Error> {
+        write!(self.0, "{value:?};")?;
+        self.1 += 1;
+        Ok(())
+    }
+
+    fn count(&self) -> u32 {
+        self.1
+    }
+}
@@ -0,0 +1,62 @@
+mod common;
+
+use common::DebugArgs;
+use sqlstr::{sql, SqlCommand, Void};
+
+struct Filter {
+    email: &'static str,
+    ids: [i64; 2],
+}
+
+#[test]
+fn interpolated_values() {
+    let filter = Filter {
+        email: "foo@bar.com",
+        ids: [3, 7],
+    };
+    let column = String::from("created_at");
+
+    let sql: SqlCommand<DebugArgs> = sql!(
+        "SELECT id, {column:raw} FROM user WHERE (email = {filter.email} OR id IN ({filter.ids[0]}, {filter.ids[1]})) AND name <> 'it''s {{x}}'"
+    )
+    .unwrap();
+
+    assert_eq!(
+        sql.as_command(),
+        "SELECT id, created_at FROM user WHERE (email = $1 OR id IN ($2, $3)) AND name <> 'it''s {x}'"
+    );
+    assert_eq!(sql.arguments.0, "\"foo@bar.com\";3;7;");
+}
+
+#[test]
+fn block_expressions() {
+    let sql: SqlCommand<DebugArgs> = sql!(
+        "UPDATE post SET edited = {Some(true)}, tags = '{{}}' WHERE id = { { let id = 5; id * 2 } }"
+    )
+    .unwrap();
+
+    assert_eq!(
+        sql.as_command(),
+        "UPDATE post SET edited = $1, tags = '{}' WHERE id = $2"
+    );
+    assert_eq!(sql.arguments.0, "Some(true);10;");
+}
+
+#[test]
+fn comments_and_strings() {
+    let id = 4;
+    let sql: SqlCommand<DebugArgs> =
+        sql!("SELECT id -- don't\nFROM t WHERE tag = $$it's$$ /* (x */ AND id = {id}").unwrap();
+
+    assert_eq!(
+        sql.as_command(),
+        "SELECT id -- don't\nFROM t WHERE tag = $$it's$$ /* (x */ AND id = $1"
+    );
+    assert_eq!(sql.arguments.0, "4;");
+}
+
+#[test]
+fn without_values() {
+    let sql: Result<SqlCommand<Void>, core::convert::Infallible> = sql!("DELETE FROM session");
+    assert_eq!(sql.unwrap().as_command(), "DELETE FROM session");
+}
@@ -0,0 +1,61 @@
+use sqlstr::{
+    expr::{from_table, select, static_columns},
+    schema::Table,
+    SqlCommand, Void,
+};
+
+#[derive(Table)]
+#[sqlstr(table = "user")]
+#[allow(dead_code)]
+pub struct User {
+    id: i64,
+    #[sqlstr(rename = "created_at")]
+    created: u64,
+    r#type: String,
+}
+
+#[derive(Table)]
+#[allow(dead_code)]
+struct OrderItem<'a> {
+    order_id: i64,
+    sku: &'a str,
+}
+
+#[derive(Table)]
+#[allow(dead_code)]
+struct HTTPLog {
+    id: i64,
+}
+
+#[test]
+fn table_definitions() {
+    assert_eq!(User::TABLE, "user");
+    assert_eq!(User::COLUMNS, static_columns!("id", "created_at", "type"));
+    assert_eq!(User::COLUMN_NAMES, ["id", "created_at", "type"]);
+    assert_eq!(User::column_list().expr().as_str(), "id, created_at, type");
+
+    assert_eq!(UserColumn::TABLE, "user");
+    assert_eq!(
+        UserColumn::ALL,
+        [UserColumn::Id, UserColumn::Created, UserColumn::Type]
+    );
+    assert_eq!(UserColumn::Type.as_str(), "type");
+}
+
+#[test]
+fn default_table_name() {
+    assert_eq!(<OrderItem<'_> as Table>::TABLE, "order_item");
+    assert_eq!(OrderItemColumn::OrderId.as_str(), "order_id");
+    assert_eq!(HTTPLog::TABLE, "http_log");
+}
+
+#[test]
+fn select_table_columns() {
+    let mut sql: SqlCommand<Void> = SqlCommand::default();
+    select(&mut sql);
+    sql.push_cmd(" ");
+    sql.push_cmd(User::COLUMNS);
+    from_table(&mut sql, User::TABLE);
+
+    assert_eq!(sql.as_command(), "SELECT id, created_at, type FROM user");
+}
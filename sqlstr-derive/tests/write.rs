@@ -0,0 +1,111 @@
+mod common;
+
+use common::DebugArgs;
+use sqlstr::{
+    expr::{filter_where, update_table},
+    schema::{AsChangeset, Insertable, Table},
+    SqlCommand,
+};
+
+#[derive(Table, Insertable)]
+#[sqlstr(table = "post")]
+#[allow(dead_code)]
+struct Post<'a> {
+    #[sqlstr(default)]
+    id: i64,
+    #[sqlstr(rename = "author_id")]
+    author: i64,
+    title: &'a str,
+    #[sqlstr(skip)]
+    cached_html: String,
+}
+
+#[derive(Table, AsChangeset)]
+#[sqlstr(table = "post")]
+struct PostPatch {
+    title: Option<String>,
+    body: Option<Option<String>>,
+    edited: bool,
+    #[sqlstr(skip)]
+    #[allow(dead_code)]
+    reason: Option<String>,
+}
+
+#[derive(Table, AsChangeset)]
+#[sqlstr(table = "post")]
+struct TitlePatch {
+    title: Option<String>,
+}
+
+fn post(id: i64, author: i64, title: &str) -> Post<'_> {
+    Post {
+        id,
+        author,
+        title,
+        cached_html: String::new(),
+    }
+}
+
+#[test]
+fn insert_rows() {
+    assert_eq!(<Post<'_> as Table>::COLUMNS, "id, author_id, title");
+
+    let mut sql: SqlCommand<DebugArgs> = SqlCommand::default();
+    Post::insert_one(&mut sql, &post(1, 7, "foo")).unwrap();
+
+    assert_eq!(
+        sql.as_command(),
+        "INSERT INTO post (author_id, title) VALUES ($1, $2)"
+    );
+    assert_eq!(sql.arguments.0, "7;\"foo\";");
+
+    let rows = [post(1, 7, "foo"), post(2, 8, "bar")];
+    let mut sql: SqlCommand<DebugArgs> = SqlCommand::default();
+    assert!(Post::insert_many(&mut sql, &rows).unwrap());
+
+    assert_eq!(
+        sql.as_command(),
+        "INSERT INTO post (author_id, title) VALUES ($1, $2), ($3, $4)"
+    );
+    assert_eq!(sql.arguments.0, "7;\"foo\";8;\"bar\";");
+}
+
+#[test]
+fn insert_no_rows() {
+    let mut sql: SqlCommand<DebugArgs> = SqlCommand::default();
+    assert!(!Post::insert_many(&mut sql, &[]).unwrap());
+
+    assert_eq!(sql.as_command(), "");
+    assert_eq!(sql.arguments.0, "");
+}
+
+#[test]
+fn update_patch() {
+    let patch = PostPatch {
+        title: None,
+        body: Some(None),
+        edited: true,
+        reason: Some("typo".into()),
+    };
+
+    let mut sql: SqlCommand<DebugArgs> = SqlCommand::default();
+    update_table(&mut sql, PostPatch::TABLE);
+    assert!(PostPatch::update_set(&mut sql, &patch).unwrap());
+    filter_where(&mut sql);
+    sql.push_cmd(" id = 1");
+
+    assert_eq!(
+        sql.as_command(),
+        "UPDATE post SET body = $1, edited = $2 WHERE id = 1"
+    );
+    assert_eq!(sql.arguments.0, "None;true;");
+}
+
+#[test]
+fn update_empty_patch() {
+    let mut sql: SqlCommand<DebugArgs> = SqlCommand::default();
+    update_table(&mut sql, TitlePatch::TABLE);
+
+    assert!(!TitlePatch::update_set(&mut sql, &TitlePatch { title: None }).unwrap());
+    assert_eq!(sql.as_command(), "UPDATE post");
+}
@@ -0,0 +1,83 @@
+//! Owned expression tree of commands.
+//!
+//! The writers of [expr](crate::expr) write into the command as soon as they
+//! are called. An [Expr] or [Statement] is built first, it can be cloned,
+//! inspected and transformed, and then it is rendered with those writers for
+//! a [Dialect](crate::Dialect).
+//!
+//! Conditions are built by chaining methods from [col], like
+//! `col("age").gte(18).and(col("name").ilike("a%"))`, operands are grouped
+//! with parentheses by the precedence of the operators.
+//!
+//! # Example
+//!
+//! ```
+//! # use sqlstr::{SqlCommand, Void, Dialect};
+//! # use sqlstr::ast::{Expr, Select, Value, RenderError};
+//! # use sqlstr::expr::Cmp;
+//! # use core::convert::Infallible;
+//! # fn main() -> Result<(), RenderError<Infallible>> {
+//! let query: Select = Select::new()
+//!     .column(Expr::column("id"))
+//!     .from("user")
+//!     .filter(Expr::binary(Expr::column("age"), Cmp::Gte, Expr::value(18.into())))
+//!     .limit(Expr::value(10.into()));
+//!
+//! let sql: SqlCommand<Void> = query.to_command(Dialect::Postgres)?;
+//! assert_eq!(sql.as_command(), "SELECT id FROM user WHERE age >= $1 LIMIT $2");
+//!
+//! let sql: SqlCommand<Void> = query.to_command(Dialect::MsSql)?;
+//! assert_eq!(sql.as_command(), "SELECT TOP ($1) id FROM user WHERE age >= $2");
+//! # Ok(())
+//! # }
+//! ```
+
+mod dsl;
+mod expr;
+mod statement;
+mod value;
+
+pub use dsl::*;
+pub use expr::*;
+pub use statement::*;
+pub use value::*;
+
+use crate::DialectError;
+
+/// Error while rendering an expression tree.
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum RenderError<E> {
+    /// Error of the argument buffer
+    Argument(E),
+    /// The dialect does not support the expression
+    Dialect(DialectError),
+    /// The statement is missing a required part
+    Incomplete(&'static str),
+}
+
+impl<E> From<DialectError> for RenderError<E> {
+    fn from(value: DialectError) -> Self {
+        Self::Dialect(value)
+    }
+}
+
+#[cfg(feature = "fmt")]
+impl<E> core::fmt::Display for RenderError<E>
+where
+    E: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Argument(err) => err.fmt(f),
+            Self::Dialect(err) => err.fmt(f),
+            Self::Incomplete(part) => {
+                f.write_str("missing ")?;
+                f.write_str(part)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> std::error::Error for RenderError<E> where E: std::error::Error {}
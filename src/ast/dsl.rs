@@ -0,0 +1,312 @@
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::ops;
+
+use super::{Expr, Value};
+use crate::expr::{
+    comparison::Pattern,
+    math::{MathBi, MathUnary},
+    Cmp, LogicBi, LogicUn,
+};
+
+/// Column expression, the start of a condition.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect};
+/// # use sqlstr::ast::{col, not, RenderError};
+/// # use sqlstr::expr::{select, from_table, filter_where};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), RenderError<Infallible>> {
+/// let cond = col("age")
+///     .gte(18)
+///     .and(col("name").ilike("a%"))
+///     .or(not(col("banned")));
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// select(&mut sql);
+/// sql.push_cmd(" id");
+/// from_table(&mut sql, "user");
+/// filter_where(&mut sql);
+/// cond.render(&mut sql, Dialect::Postgres)?;
+///
+/// assert_eq!(
+///     sql.as_command(),
+///     "SELECT id FROM user WHERE age >= $1 AND name ILIKE $2 OR NOT banned"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Operands are grouped with parentheses only when the precedence of the
+/// operators requires it:
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect};
+/// # use sqlstr::ast::{col, RenderError};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), RenderError<Infallible>> {
+/// let cond = col("a")
+///     .eq(1)
+///     .or(col("b").eq(2))
+///     .and((col("c") - col("d") - 3).lt(col("e") * (col("f") + 4)));
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// cond.render(&mut sql, Dialect::Postgres)?;
+///
+/// assert_eq!(
+///     sql.as_command(),
+///     "(a = $1 OR b = $2) AND c - d - $3 < e * (f + $4)"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn col<T, C>(column: C) -> Expr<T>
+where
+    C: Into<Cow<'static, str>>,
+{
+    Expr::column(column)
+}
+
+/// Value expression, bound as an argument.
+pub fn val<T, V>(value: V) -> Expr<T>
+where
+    V: Into<T>,
+{
+    Expr::value(value.into())
+}
+
+/// `NOT <expr>` condition.
+pub fn not<T, E>(expr: E) -> Expr<T>
+where
+    E: Into<Expr<T>>,
+{
+    Expr::unary(LogicUn::Not, expr.into())
+}
+
+impl<T> Expr<T> {
+    pub fn cmp<R>(self, op: Cmp, rhs: R) -> Self
+    where
+        R: Into<Self>,
+    {
+        Self::binary(self, op, rhs.into())
+    }
+
+    /// `<self> = <rhs>`
+    pub fn eq<R>(self, rhs: R) -> Self
+    where
+        R: Into<Self>,
+    {
+        self.cmp(Cmp::Eq, rhs)
+    }
+
+    /// `<self> <> <rhs>`
+    pub fn neq<R>(self, rhs: R) -> Self
+    where
+        R: Into<Self>,
+    {
+        self.cmp(Cmp::Neq, rhs)
+    }
+
+    /// `<self> > <rhs>`
+    pub fn gt<R>(self, rhs: R) -> Self
+    where
+        R: Into<Self>,
+    {
+        self.cmp(Cmp::Gt, rhs)
+    }
+
+    /// `<self> >= <rhs>`
+    pub fn gte<R>(self, rhs: R) -> Self
+    where
+        R: Into<Self>,
+    {
+        self.cmp(Cmp::Gte, rhs)
+    }
+
+    /// `<self> < <rhs>`
+    pub fn lt<R>(self, rhs: R) -> Self
+    where
+        R: Into<Self>,
+    {
+        self.cmp(Cmp::Lt, rhs)
+    }
+
+    /// `<self> <= <rhs>`
+    pub fn lte<R>(self, rhs: R) -> Self
+    where
+        R: Into<Self>,
+    {
+        self.cmp(Cmp::Lte, rhs)
+    }
+
+    /// `<self> AND <rhs>`
+    pub fn and<R>(self, rhs: R) -> Self
+    where
+        R: Into<Self>,
+    {
+        Self::binary(self, LogicBi::And, rhs.into())
+    }
+
+    /// `<self> OR <rhs>`
+    pub fn or<R>(self, rhs: R) -> Self
+    where
+        R: Into<Self>,
+    {
+        Self::binary(self, LogicBi::Or, rhs.into())
+    }
+
+    /// `<self> LIKE <pattern>`
+    pub fn like<R>(self, pattern: R) -> Self
+    where
+        R: Into<Self>,
+    {
+        Self::binary(self, Pattern::Like, pattern.into())
+    }
+
+    /// `<self> NOT LIKE <pattern>`
+    pub fn not_like<R>(self, pattern: R) -> Self
+    where
+        R: Into<Self>,
+    {
+        Self::binary(self, Pattern::NotLike, pattern.into())
+    }
+
+    /// `<self> ILIKE <pattern>`, emulated with `LOWER` outside of Postgres
+    pub fn ilike<R>(self, pattern: R) -> Self
+    where
+        R: Into<Self>,
+    {
+        Self::binary(self, Pattern::ILike, pattern.into())
+    }
+
+    /// `<self> NOT ILIKE <pattern>`, emulated with `LOWER` outside of
+    /// Postgres
+    pub fn not_ilike<R>(self, pattern: R) -> Self
+    where
+        R: Into<Self>,
+    {
+        Self::binary(self, Pattern::NotILike, pattern.into())
+    }
+}
+
+macro_rules! expr_math {
+    ($($trait:ident::$method:ident => $op:expr),+ $(,)?) => {
+        $(
+            impl<T, R> ops::$trait<R> for Expr<T>
+            where
+                R: Into<Expr<T>>,
+            {
+                type Output = Self;
+
+                fn $method(self, rhs: R) -> Self::Output {
+                    Self::binary(self, $op, rhs.into())
+                }
+            }
+        )+
+    };
+}
+
+expr_math!(
+    Add::add => MathBi::Add,
+    Sub::sub => MathBi::Sub,
+    Mul::mul => MathBi::Mult,
+    Div::div => MathBi::Div,
+    Rem::rem => MathBi::Mod,
+);
+
+impl<T> ops::Neg for Expr<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::unary(MathUnary::Neg, self)
+    }
+}
+
+impl<T> ops::Not for Expr<T> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self::unary(LogicUn::Not, self)
+    }
+}
+
+macro_rules! expr_from_value {
+    ($($ty:ty),+) => {
+        $(
+            impl From<$ty> for Expr<Value> {
+                fn from(value: $ty) -> Self {
+                    Self::Value(value.into())
+                }
+            }
+        )+
+    };
+}
+
+expr_from_value!(Value, bool, i8, i16, i32, i64, u8, u16, u32, f32, f64);
+expr_from_value!(&str, String, &[u8], Vec<u8>);
+
+impl<V> From<Option<V>> for Expr<Value>
+where
+    V: Into<Value>,
+{
+    fn from(value: Option<V>) -> Self {
+        Self::Value(value.into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::TestArgs, Dialect, SqlCommand};
+
+    fn render(expr: &Expr) -> SqlCommand<TestArgs> {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        expr.render(&mut sql, Dialect::Postgres).unwrap();
+        sql
+    }
+
+    #[test]
+    fn precedence_groups() {
+        let cond = col("a").or(col("b")).and(not(col("c").and(col("d"))));
+        assert_eq!(render(&cond).as_command(), "(a OR b) AND NOT (c AND d)");
+
+        let cond = col("a").and(col("b")).and(col("c").and(col("d")));
+        assert_eq!(render(&cond).as_command(), "a AND b AND c AND d");
+
+        let cond = col("a").eq(col("b")).eq(true);
+        assert_eq!(render(&cond).as_command(), "(a = b) = $1");
+
+        let cond = (col("a") - (col("b") - 1)).gt(-col("c") / 2);
+        assert_eq!(render(&cond).as_command(), "a - (b - $1) > - c / $2");
+
+        let cond = col("a").is_null().eq(false);
+        assert_eq!(render(&cond).as_command(), "(a IS NULL) = $1");
+    }
+
+    #[test]
+    fn bitwise_groups() {
+        let mask = Expr::binary(col("flags"), MathBi::BitAnd, col("mask") + 1);
+        let cond = Expr::binary(mask, MathBi::BitOr, col("extra")).neq(0);
+        assert_eq!(
+            render(&cond).as_command(),
+            "((flags & (mask + $1)) | extra) <> $2"
+        );
+    }
+
+    #[test]
+    fn condition_values() {
+        let cond = col("name")
+            .like("a%")
+            .and(col("email").not_like(None::<&str>))
+            .and(col("age").between(18, 65))
+            .and(col("role").in_list(["admin", "staff"]));
+
+        let sql = render(&cond);
+        assert_eq!(
+            sql.as_command(),
+            "name LIKE $1 AND email NOT LIKE $2 AND age BETWEEN $3 AND $4 AND role IN ($5, $6)"
+        );
+        assert_eq!(sql.arguments.as_str(), "a%;NULL;18;65;admin;staff;");
+    }
+}
@@ -0,0 +1,669 @@
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
+
+use super::{RenderError, Select, Value};
+use crate::{
+    expr::{
+        column,
+        comparison::{is_in, is_not_null, is_null, Pattern},
+        math::{MathBi, MathUnary},
+        separator_optional, BinaryOperator, Cmp, Group, LogicBi, LogicUn, UnaryOperator,
+    },
+    ArgumentBuffer, Dialect, DialectError, WriteSql,
+};
+
+/// Binary operator of an [Expr]
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Cmp(Cmp),
+    Logic(LogicBi),
+    Math(MathBi),
+    Pattern(Pattern),
+}
+
+impl BinaryOp {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Cmp(op) => op.as_str(),
+            Self::Logic(op) => op.as_str(),
+            Self::Math(op) => op.as_str(),
+            Self::Pattern(op) => op.as_str(),
+        }
+    }
+
+    /// Precedence of the operator, from the operator precedence of Postgres
+    const fn precedence(&self) -> u8 {
+        match self {
+            Self::Logic(LogicBi::Or) => 1,
+            Self::Logic(LogicBi::And) => 2,
+            Self::Cmp(_) => 5,
+            Self::Pattern(_) => 6,
+            Self::Math(MathBi::Add | MathBi::Sub) => 8,
+            Self::Math(MathBi::Mult | MathBi::Div | MathBi::Mod) => 9,
+            Self::Math(_) => 7,
+        }
+    }
+
+    const fn is_bitwise(&self) -> bool {
+        matches!(
+            self,
+            Self::Math(
+                MathBi::BitAnd
+                    | MathBi::BitOr
+                    | MathBi::BitXor
+                    | MathBi::ShiftLeft
+                    | MathBi::ShiftRight
+            )
+        )
+    }
+
+    /// `(a <op> b) <op> c` is the same as `a <op> (b <op> c)`
+    const fn is_associative(&self) -> bool {
+        matches!(
+            self,
+            Self::Logic(_)
+                | Self::Math(MathBi::Add | MathBi::Mult | MathBi::BitAnd | MathBi::BitOr)
+        )
+    }
+
+    fn push_operator<Sql, Arg>(&self, sql: &mut Sql)
+    where
+        Sql: WriteSql<Arg>,
+    {
+        match self {
+            Self::Cmp(op) => op.push_operator(sql),
+            Self::Logic(op) => op.push_operator(sql),
+            Self::Math(op) => op.push_operator(sql),
+            Self::Pattern(op) => op.push_operator(sql),
+        }
+    }
+}
+
+impl From<Cmp> for BinaryOp {
+    fn from(value: Cmp) -> Self {
+        Self::Cmp(value)
+    }
+}
+
+impl From<LogicBi> for BinaryOp {
+    fn from(value: LogicBi) -> Self {
+        Self::Logic(value)
+    }
+}
+
+impl From<MathBi> for BinaryOp {
+    fn from(value: MathBi) -> Self {
+        Self::Math(value)
+    }
+}
+
+impl From<Pattern> for BinaryOp {
+    fn from(value: Pattern) -> Self {
+        Self::Pattern(value)
+    }
+}
+
+/// Unary operator of an [Expr]
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum UnaryOp {
+    Logic(LogicUn),
+    Math(MathUnary),
+}
+
+impl UnaryOp {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Logic(op) => op.as_str(),
+            Self::Math(op) => op.as_str(),
+        }
+    }
+
+    fn push_operator<Sql, Arg>(&self, sql: &mut Sql)
+    where
+        Sql: WriteSql<Arg>,
+    {
+        match self {
+            Self::Logic(op) => op.push_operator(sql),
+            Self::Math(op) => op.push_operator(sql),
+        }
+    }
+}
+
+impl From<LogicUn> for UnaryOp {
+    fn from(value: LogicUn) -> Self {
+        Self::Logic(value)
+    }
+}
+
+impl From<MathUnary> for UnaryOp {
+    fn from(value: MathUnary) -> Self {
+        Self::Math(value)
+    }
+}
+
+/// Owned expression, with values of type `T`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect};
+/// # use sqlstr::ast::{Expr, Value, RenderError};
+/// # use sqlstr::expr::{comparison::Pattern, LogicBi};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), RenderError<Infallible>> {
+/// let name: Expr = Expr::binary(
+///     Expr::column("name"),
+///     Pattern::ILike,
+///     Expr::value("a%".into()),
+/// );
+/// let cond = Expr::binary(name, LogicBi::And, Expr::column("active").is_not_null());
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// cond.render(&mut sql, Dialect::Postgres)?;
+/// assert_eq!(sql.as_command(), "name ILIKE $1 AND active IS NOT NULL");
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// cond.render(&mut sql, Dialect::MySql)?;
+/// assert_eq!(
+///     sql.as_command(),
+///     "LOWER(name) LIKE LOWER($1) AND active IS NOT NULL"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq)]
+pub enum Expr<T = Value> {
+    /// Column, written as is
+    Column(Cow<'static, str>),
+    /// Value bound as an argument
+    Value(T),
+    /// Trusted sql, written as is
+    Raw(Cow<'static, str>),
+    /// `<lhs> <op> <rhs>`
+    Binary(Box<Expr<T>>, BinaryOp, Box<Expr<T>>),
+    /// `<op> <expr>`
+    Unary(UnaryOp, Box<Expr<T>>),
+    /// `<expr> IS NULL`
+    IsNull(Box<Expr<T>>),
+    /// `<expr> IS NOT NULL`
+    IsNotNull(Box<Expr<T>>),
+    /// `<expr> IN (<list>)`
+    In(Box<Expr<T>>, Vec<Expr<T>>),
+    /// `<expr> BETWEEN <low> AND <high>`
+    Between(Box<Expr<T>>, Box<Expr<T>>, Box<Expr<T>>),
+    /// `<name>(<args>)`
+    Function(Cow<'static, str>, Vec<Expr<T>>),
+    /// `CAST (<expr> AS <type>)`
+    Cast(Box<Expr<T>>, Cow<'static, str>),
+    /// `(<select>)`
+    Subquery(Box<Select<T>>),
+    /// `EXISTS (<select>)`
+    Exists(Box<Select<T>>),
+}
+
+impl<T> Expr<T> {
+    pub fn column<C>(column: C) -> Self
+    where
+        C: Into<Cow<'static, str>>,
+    {
+        Self::Column(column.into())
+    }
+
+    pub const fn value(value: T) -> Self {
+        Self::Value(value)
+    }
+
+    pub fn raw<S>(sql: S) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self::Raw(sql.into())
+    }
+
+    pub fn binary<Op>(lhs: Self, op: Op, rhs: Self) -> Self
+    where
+        Op: Into<BinaryOp>,
+    {
+        Self::Binary(Box::new(lhs), op.into(), Box::new(rhs))
+    }
+
+    pub fn unary<Op>(op: Op, expr: Self) -> Self
+    where
+        Op: Into<UnaryOp>,
+    {
+        Self::Unary(op.into(), Box::new(expr))
+    }
+
+    pub fn function<N, I>(name: N, args: I) -> Self
+    where
+        N: Into<Cow<'static, str>>,
+        I: IntoIterator<Item = Self>,
+    {
+        Self::Function(name.into(), args.into_iter().collect())
+    }
+
+    pub fn cast<S>(self, typ: S) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        Self::Cast(Box::new(self), typ.into())
+    }
+
+    pub fn subquery(select: Select<T>) -> Self {
+        Self::Subquery(Box::new(select))
+    }
+
+    pub fn exists(select: Select<T>) -> Self {
+        Self::Exists(Box::new(select))
+    }
+
+    pub fn is_null(self) -> Self {
+        Self::IsNull(Box::new(self))
+    }
+
+    pub fn is_not_null(self) -> Self {
+        Self::IsNotNull(Box::new(self))
+    }
+
+    pub fn in_list<I>(self, list: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Self>,
+    {
+        Self::In(Box::new(self), list.into_iter().map(Into::into).collect())
+    }
+
+    pub fn between<L, H>(self, low: L, high: H) -> Self
+    where
+        L: Into<Self>,
+        H: Into<Self>,
+    {
+        Self::Between(Box::new(self), Box::new(low.into()), Box::new(high.into()))
+    }
+
+    /// Rewrites the expression bottom-up: `f` is called with every
+    /// subexpression, after its children were rewritten, and its result
+    /// replaces the subexpression.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use sqlstr::ast::{Expr, Value};
+    /// # use sqlstr::expr::Cmp;
+    /// let cond: Expr = Expr::binary(Expr::column("id"), Cmp::Eq, Expr::value(1.into()));
+    /// let qualified = cond.transform(&mut |expr| match expr {
+    ///     Expr::Column(column) => Expr::column(format!("user.{column}")),
+    ///     expr => expr,
+    /// });
+    ///
+    /// assert_eq!(
+    ///     qualified,
+    ///     Expr::binary(Expr::column("user.id"), Cmp::Eq, Expr::value(1.into()))
+    /// );
+    /// ```
+    pub fn transform<F>(self, f: &mut F) -> Self
+    where
+        F: FnMut(Self) -> Self,
+    {
+        let expr = match self {
+            expr @ (Self::Column(_) | Self::Value(_) | Self::Raw(_)) => expr,
+            Self::Binary(lhs, op, rhs) => {
+                Self::Binary(Box::new(lhs.transform(f)), op, Box::new(rhs.transform(f)))
+            }
+            Self::Unary(op, expr) => Self::Unary(op, Box::new(expr.transform(f))),
+            Self::IsNull(expr) => Self::IsNull(Box::new(expr.transform(f))),
+            Self::IsNotNull(expr) => Self::IsNotNull(Box::new(expr.transform(f))),
+            Self::In(expr, list) => Self::In(
+                Box::new(expr.transform(f)),
+                list.into_iter().map(|item| item.transform(f)).collect(),
+            ),
+            Self::Between(expr, low, high) => Self::Between(
+                Box::new(expr.transform(f)),
+                Box::new(low.transform(f)),
+                Box::new(high.transform(f)),
+            ),
+            Self::Function(name, args) => {
+                Self::Function(name, args.into_iter().map(|arg| arg.transform(f)).collect())
+            }
+            Self::Cast(expr, typ) => Self::Cast(Box::new(expr.transform(f)), typ),
+            Self::Subquery(select) => Self::Subquery(Box::new(select.transform(f))),
+            Self::Exists(select) => Self::Exists(Box::new(select.transform(f))),
+        };
+        f(expr)
+    }
+
+    /// Calls `f` with the expression and every subexpression, parents
+    /// before their children.
+    pub fn visit<F>(&self, f: &mut F)
+    where
+        F: FnMut(&Self),
+    {
+        f(self);
+        match self {
+            Self::Column(_) | Self::Value(_) | Self::Raw(_) => {}
+            Self::Binary(lhs, _, rhs) => {
+                lhs.visit(f);
+                rhs.visit(f);
+            }
+            Self::Unary(_, expr)
+            | Self::IsNull(expr)
+            | Self::IsNotNull(expr)
+            | Self::Cast(expr, _) => expr.visit(f),
+            Self::In(expr, list) => {
+                expr.visit(f);
+                list.iter().for_each(|item| item.visit(f));
+            }
+            Self::Between(expr, low, high) => {
+                expr.visit(f);
+                low.visit(f);
+                high.visit(f);
+            }
+            Self::Function(_, args) => args.iter().for_each(|arg| arg.visit(f)),
+            Self::Subquery(select) | Self::Exists(select) => select.visit(f),
+        }
+    }
+
+    /// Writes the expression into the sql command, values are cloned into
+    /// the argument buffer.
+    pub fn render<Sql, Arg>(
+        &self,
+        sql: &mut Sql,
+        dialect: Dialect,
+    ) -> Result<(), RenderError<Arg::Error>>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+        T: Clone,
+    {
+        match self {
+            Self::Column(name) | Self::Raw(name) => column(sql, name),
+            Self::Value(value) => {
+                separator_optional(sql);
+                sql.push_value(value.clone())
+                    .map_err(RenderError::Argument)?;
+            }
+            Self::Binary(lhs, BinaryOp::Pattern(op), rhs) if dialect != Dialect::Postgres => {
+                // case insensitive patterns are emulated with `LOWER`
+                let (op, lower) = match op {
+                    Pattern::Like | Pattern::NotLike => (*op, false),
+                    Pattern::ILike => (Pattern::Like, true),
+                    Pattern::NotILike => (Pattern::NotLike, true),
+                    Pattern::Similar | Pattern::NotSimilar => {
+                        return Err(DialectError::Unsupported(op.as_str()).into())
+                    }
+                };
+                lhs.render_pattern_operand(sql, dialect, self, lower)?;
+                separator_optional(sql);
+                op.push_operator(sql);
+                rhs.render_pattern_operand(sql, dialect, self, lower)?;
+            }
+            Self::Binary(lhs, op, rhs) => {
+                lhs.render_operand(sql, dialect, self, Side::Left)?;
+                separator_optional(sql);
+                op.push_operator(sql);
+                rhs.render_operand(sql, dialect, self, Side::Right)?;
+            }
+            Self::Unary(op, expr) => {
+                separator_optional(sql);
+                op.push_operator(sql);
+                expr.render_operand(sql, dialect, self, Side::Right)?;
+            }
+            Self::IsNull(expr) => {
+                expr.render_operand(sql, dialect, self, Side::Left)?;
+                is_null(sql);
+            }
+            Self::IsNotNull(expr) => {
+                expr.render_operand(sql, dialect, self, Side::Left)?;
+                is_not_null(sql);
+            }
+            Self::In(expr, list) => {
+                expr.render_operand(sql, dialect, self, Side::Left)?;
+                let mut group = is_in(sql);
+                render_list(group.sql(), dialect, list)?;
+            }
+            Self::Between(expr, low, high) => {
+                expr.render_operand(sql, dialect, self, Side::Left)?;
+                separator_optional(sql);
+                sql.push_cmd("BETWEEN");
+                low.render_operand(sql, dialect, self, Side::Right)?;
+                sql.push_cmd(" AND");
+                high.render_operand(sql, dialect, self, Side::Right)?;
+            }
+            Self::Function(name, args) => {
+                separator_optional(sql);
+                sql.push_cmd(name);
+                sql.push_cmd("(");
+                render_list(sql, dialect, args)?;
+                sql.push_cmd(")");
+            }
+            Self::Cast(expr, typ) => {
+                separator_optional(sql);
+                sql.push_cmd("CAST (");
+                expr.render(sql, dialect)?;
+                sql.push_cmd(" AS ");
+                sql.push_cmd(typ);
+                sql.push_cmd(")");
+            }
+            Self::Subquery(select) => {
+                let mut group = Group::open(sql);
+                select.render(group.sql(), dialect)?;
+            }
+            Self::Exists(select) => {
+                separator_optional(sql);
+                sql.push_cmd("EXISTS");
+                let mut group = Group::open(sql);
+                select.render(group.sql(), dialect)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Binding strength of the operator of the expression, higher binds
+    /// tighter, from the operator precedence of Postgres. `None` for the
+    /// expressions that are never grouped with parentheses.
+    fn precedence(&self) -> Option<u8> {
+        match self {
+            Self::Binary(_, op, _) => Some(op.precedence()),
+            Self::Unary(UnaryOp::Logic(_), _) => Some(3),
+            Self::Unary(UnaryOp::Math(_), _) => Some(11),
+            Self::IsNull(_) | Self::IsNotNull(_) => Some(4),
+            Self::In(..) | Self::Between(..) => Some(6),
+            Self::Column(_)
+            | Self::Value(_)
+            | Self::Raw(_)
+            | Self::Function(..)
+            | Self::Cast(..)
+            | Self::Subquery(_)
+            | Self::Exists(_) => None,
+        }
+    }
+
+    /// An operand is grouped with parentheses when it binds weaker than the
+    /// operator of the parent, or as strong but can not be chained.
+    fn needs_group(&self, parent: &Self, side: Side) -> bool {
+        let (Some(precedence), Some(parent_precedence)) = (self.precedence(), parent.precedence())
+        else {
+            return false;
+        };
+
+        match (self, parent) {
+            (Self::Binary(_, op, _), Self::Binary(_, parent_op, _)) => {
+                // the precedence of the bitwise operators differs by dialect
+                if op.is_bitwise() || parent_op.is_bitwise() {
+                    return !(op == parent_op && (side == Side::Left || op.is_associative()));
+                }
+                if precedence != parent_precedence {
+                    return precedence < parent_precedence;
+                }
+                if op == parent_op && op.is_associative() {
+                    return false;
+                }
+                // left associative arithmetic
+                !(side == Side::Left && matches!(op, BinaryOp::Math(_)))
+            }
+            (Self::Unary(..), Self::Unary(..)) => false,
+            _ => precedence <= parent_precedence,
+        }
+    }
+
+    /// Writes an operand of the `parent` expression, grouped with
+    /// parentheses when required by the precedence of the operators.
+    fn render_operand<Sql, Arg>(
+        &self,
+        sql: &mut Sql,
+        dialect: Dialect,
+        parent: &Self,
+        side: Side,
+    ) -> Result<(), RenderError<Arg::Error>>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+        T: Clone,
+    {
+        if self.needs_group(parent, side) {
+            let mut group = Group::open(sql);
+            self.render(group.sql(), dialect)
+        } else {
+            self.render(sql, dialect)
+        }
+    }
+
+    /// Writes an operand of a case insensitive pattern emulated with
+    /// `LOWER(<operand>)`.
+    fn render_pattern_operand<Sql, Arg>(
+        &self,
+        sql: &mut Sql,
+        dialect: Dialect,
+        parent: &Self,
+        lower: bool,
+    ) -> Result<(), RenderError<Arg::Error>>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+        T: Clone,
+    {
+        if !lower {
+            return self.render_operand(sql, dialect, parent, Side::Left);
+        }
+
+        separator_optional(sql);
+        sql.push_cmd("LOWER(");
+        self.render(sql, dialect)?;
+        sql.push_cmd(")");
+        Ok(())
+    }
+}
+
+/// Side of an operand of a binary operator
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Left,
+    Right,
+}
+
+/// Writes a list of expressions separated by commas.
+pub(super) fn render_list<Sql, Arg, T>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    list: &[Expr<T>],
+) -> Result<(), RenderError<Arg::Error>>
+where
+    Sql: WriteSql<Arg>,
+    Arg: ArgumentBuffer<T>,
+    T: Clone,
+{
+    let Some((first, rest)) = list.split_first() else {
+        return Ok(());
+    };
+
+    first.render(sql, dialect)?;
+    for item in rest {
+        sql.push_cmd(",");
+        item.render(sql, dialect)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::TestArgs, SqlCommand};
+
+    fn render(expr: &Expr, dialect: Dialect) -> SqlCommand<TestArgs> {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        expr.render(&mut sql, dialect).unwrap();
+        sql
+    }
+
+    #[test]
+    fn nested_operands() {
+        let expr: Expr = Expr::binary(
+            Expr::binary(Expr::column("a"), MathBi::Add, Expr::value(1.into())),
+            Cmp::Gt,
+            Expr::function("abs", [Expr::column("b"), Expr::raw("2")]).cast("int8"),
+        );
+        let expr = Expr::binary(
+            Expr::unary(LogicUn::Not, expr),
+            LogicBi::Or,
+            Expr::column("c")
+                .in_list([Expr::value("x".into()), Expr::value("y".into())])
+                .between(Expr::value(Value::Null), Expr::column("d")),
+        );
+
+        let sql = render(&expr, Dialect::Postgres);
+        assert_eq!(
+            sql.as_command(),
+            "NOT a + $1 > CAST (abs(b, 2) AS int8) OR (c IN ($2, $3)) BETWEEN $4 AND d"
+        );
+        assert_eq!(sql.arguments.as_str(), "1;x;y;NULL;");
+    }
+
+    #[test]
+    fn pattern_dialect() {
+        let expr: Expr = Expr::binary(
+            Expr::column("name"),
+            Pattern::NotILike,
+            Expr::value("%a".into()),
+        );
+        assert_eq!(
+            render(&expr, Dialect::Sqlite).as_command(),
+            "LOWER(name) NOT LIKE LOWER($1)"
+        );
+
+        let expr: Expr = Expr::binary(
+            Expr::column("name"),
+            Pattern::Similar,
+            Expr::value("%(b|d)%".into()),
+        );
+        assert_eq!(
+            render(&expr, Dialect::Postgres).as_command(),
+            "name SIMILAR TO $1"
+        );
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        assert_eq!(
+            expr.render(&mut sql, Dialect::MsSql),
+            Err(RenderError::Dialect(DialectError::Unsupported(
+                "SIMILAR TO"
+            )))
+        );
+    }
+
+    #[test]
+    fn visit_values() {
+        let expr: Expr = Expr::binary(
+            Expr::column("a").is_null(),
+            LogicBi::And,
+            Expr::binary(Expr::column("b"), Cmp::Eq, Expr::value(3.into())),
+        );
+
+        let mut values = Vec::new();
+        expr.visit(&mut |expr| {
+            if let Expr::Value(value) = expr {
+                values.push(value.clone());
+            }
+        });
+        assert_eq!(values, [Value::Int(3)]);
+    }
+}
@@ -0,0 +1,1169 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+use super::{expr::render_list, Expr, RenderError, Value};
+use crate::{
+    expr::{
+        column, column_list, columns_iter, delete_from, filter_having, filter_where, from_tables,
+        group_by, insert_into, join, join_on, order_by, output_rows, returning_rows, select,
+        select_distinct, separator_optional, set_column, set_update, update_table, values,
+        ColumnExprList, Group, JoinType, LogicBi, OrderByNulls, OrderByOrd, ReturningCommand,
+    },
+    ArgumentBuffer, Dialect, DialectError, SqlCommand, WriteSql,
+};
+
+/// Table of a `FROM` or `JOIN` clause, with an optional alias
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct TableRef {
+    pub name: Cow<'static, str>,
+    pub alias: Option<Cow<'static, str>>,
+}
+
+impl TableRef {
+    pub fn new<N>(name: N) -> Self
+    where
+        N: Into<Cow<'static, str>>,
+    {
+        Self {
+            name: name.into(),
+            alias: None,
+        }
+    }
+
+    pub fn alias<A>(mut self, alias: A) -> Self
+    where
+        A: Into<Cow<'static, str>>,
+    {
+        self.alias = Some(alias.into());
+        self
+    }
+
+    fn render<Sql, Arg>(&self, sql: &mut Sql)
+    where
+        Sql: WriteSql<Arg>,
+    {
+        column(sql, &self.name);
+        if let Some(alias) = &self.alias {
+            sql.push_cmd(" AS ");
+            sql.push_cmd(alias);
+        }
+    }
+}
+
+impl From<&'static str> for TableRef {
+    fn from(value: &'static str) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Selected expression of a `SELECT`, with an optional alias
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq)]
+pub struct SelectItem<T = Value> {
+    pub expr: Expr<T>,
+    pub alias: Option<Cow<'static, str>>,
+}
+
+/// `JOIN` clause of a `SELECT`, a `CROSS` join has no condition
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq)]
+pub struct Join<T = Value> {
+    pub typ: JoinType,
+    pub table: TableRef,
+    pub on: Option<Expr<T>>,
+}
+
+/// Order expression of a `ORDER BY` clause
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq)]
+pub struct OrderBy<T = Value> {
+    pub expr: Expr<T>,
+    pub order: OrderByOrd<'static>,
+    pub nulls: OrderByNulls,
+}
+
+/// `SELECT` query
+///
+/// Without columns, all the columns are selected with `*`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect};
+/// # use sqlstr::ast::{Expr, Select, TableRef, RenderError};
+/// # use sqlstr::expr::{Cmp, JoinType, OrderByOrd, OrderByNulls};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), RenderError<Infallible>> {
+/// let query: Select = Select::new()
+///     .column(Expr::column("u.id"))
+///     .column_as(Expr::function("count", [Expr::column("p.id")]), "posts")
+///     .from(TableRef::new("user").alias("u"))
+///     .join(
+///         JoinType::Left,
+///         TableRef::new("post").alias("p"),
+///         Expr::binary(Expr::column("p.user_id"), Cmp::Eq, Expr::column("u.id")),
+///     )
+///     .group_by(Expr::column("u.id"))
+///     .order_by(Expr::column("posts"), OrderByOrd::Desc, OrderByNulls::Default)
+///     .limit(Expr::value(10.into()))
+///     .offset(Expr::value(20.into()));
+///
+/// let sql: SqlCommand<Void> = query.to_command(Dialect::Postgres)?;
+/// assert_eq!(
+///     sql.as_command(),
+///     "SELECT u.id, count(p.id) AS posts FROM user AS u \
+///      LEFT JOIN post AS p ON p.user_id = u.id GROUP BY u.id \
+///      ORDER BY posts DESC LIMIT $1 OFFSET $2"
+/// );
+///
+/// let sql: SqlCommand<Void> = query.to_command(Dialect::MsSql)?;
+/// assert_eq!(
+///     sql.as_command(),
+///     "SELECT u.id, count(p.id) AS posts FROM user AS u \
+///      LEFT JOIN post AS p ON p.user_id = u.id GROUP BY u.id \
+///      ORDER BY posts DESC OFFSET $1 ROWS FETCH NEXT $2 ROWS ONLY"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq)]
+pub struct Select<T = Value> {
+    pub distinct: bool,
+    pub columns: Vec<SelectItem<T>>,
+    pub from: Vec<TableRef>,
+    pub joins: Vec<Join<T>>,
+    pub filter: Option<Expr<T>>,
+    pub group_by: Vec<Expr<T>>,
+    pub having: Option<Expr<T>>,
+    pub order_by: Vec<OrderBy<T>>,
+    pub limit: Option<Expr<T>>,
+    pub offset: Option<Expr<T>>,
+}
+
+impl<T> Default for Select<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Select<T> {
+    pub const fn new() -> Self {
+        Self {
+            distinct: false,
+            columns: Vec::new(),
+            from: Vec::new(),
+            joins: Vec::new(),
+            filter: None,
+            group_by: Vec::new(),
+            having: None,
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+        }
+    }
+
+    pub fn distinct(mut self) -> Self {
+        self.distinct = true;
+        self
+    }
+
+    pub fn column(mut self, expr: Expr<T>) -> Self {
+        self.columns.push(SelectItem { expr, alias: None });
+        self
+    }
+
+    pub fn column_as<A>(mut self, expr: Expr<T>, alias: A) -> Self
+    where
+        A: Into<Cow<'static, str>>,
+    {
+        self.columns.push(SelectItem {
+            expr,
+            alias: Some(alias.into()),
+        });
+        self
+    }
+
+    pub fn from<Tbl>(mut self, table: Tbl) -> Self
+    where
+        Tbl: Into<TableRef>,
+    {
+        self.from.push(table.into());
+        self
+    }
+
+    pub fn join<Tbl>(mut self, typ: JoinType, table: Tbl, on: Expr<T>) -> Self
+    where
+        Tbl: Into<TableRef>,
+    {
+        self.joins.push(Join {
+            typ,
+            table: table.into(),
+            on: Some(on),
+        });
+        self
+    }
+
+    pub fn cross_join<Tbl>(mut self, table: Tbl) -> Self
+    where
+        Tbl: Into<TableRef>,
+    {
+        self.joins.push(Join {
+            typ: JoinType::Cross,
+            table: table.into(),
+            on: None,
+        });
+        self
+    }
+
+    /// Adds a `WHERE` condition, joined with `AND` to the previous ones.
+    pub fn filter(mut self, condition: Expr<T>) -> Self {
+        self.filter = Some(and_condition(self.filter.take(), condition));
+        self
+    }
+
+    pub fn group_by(mut self, expr: Expr<T>) -> Self {
+        self.group_by.push(expr);
+        self
+    }
+
+    /// Adds a `HAVING` condition, joined with `AND` to the previous ones.
+    pub fn having(mut self, condition: Expr<T>) -> Self {
+        self.having = Some(and_condition(self.having.take(), condition));
+        self
+    }
+
+    pub fn order_by(
+        mut self,
+        expr: Expr<T>,
+        order: OrderByOrd<'static>,
+        nulls: OrderByNulls,
+    ) -> Self {
+        self.order_by.push(OrderBy { expr, order, nulls });
+        self
+    }
+
+    pub fn limit(mut self, count: Expr<T>) -> Self {
+        self.limit = Some(count);
+        self
+    }
+
+    pub fn offset(mut self, start: Expr<T>) -> Self {
+        self.offset = Some(start);
+        self
+    }
+
+    /// Rewrites every expression of the query with [Expr::transform].
+    pub fn transform<F>(self, f: &mut F) -> Self
+    where
+        F: FnMut(Expr<T>) -> Expr<T>,
+    {
+        Self {
+            distinct: self.distinct,
+            columns: self
+                .columns
+                .into_iter()
+                .map(|item| SelectItem {
+                    expr: item.expr.transform(f),
+                    alias: item.alias,
+                })
+                .collect(),
+            from: self.from,
+            joins: self
+                .joins
+                .into_iter()
+                .map(|join| Join {
+                    typ: join.typ,
+                    table: join.table,
+                    on: join.on.map(|on| on.transform(f)),
+                })
+                .collect(),
+            filter: self.filter.map(|filter| filter.transform(f)),
+            group_by: self
+                .group_by
+                .into_iter()
+                .map(|expr| expr.transform(f))
+                .collect(),
+            having: self.having.map(|having| having.transform(f)),
+            order_by: self
+                .order_by
+                .into_iter()
+                .map(|order| OrderBy {
+                    expr: order.expr.transform(f),
+                    order: order.order,
+                    nulls: order.nulls,
+                })
+                .collect(),
+            limit: self.limit.map(|limit| limit.transform(f)),
+            offset: self.offset.map(|offset| offset.transform(f)),
+        }
+    }
+
+    /// Visits every expression of the query with [Expr::visit].
+    pub fn visit<F>(&self, f: &mut F)
+    where
+        F: FnMut(&Expr<T>),
+    {
+        self.columns.iter().for_each(|item| item.expr.visit(f));
+        self.joins
+            .iter()
+            .filter_map(|join| join.on.as_ref())
+            .for_each(|on| on.visit(f));
+        self.filter.iter().for_each(|filter| filter.visit(f));
+        self.group_by.iter().for_each(|expr| expr.visit(f));
+        self.having.iter().for_each(|having| having.visit(f));
+        self.order_by.iter().for_each(|order| order.expr.visit(f));
+        self.limit.iter().for_each(|limit| limit.visit(f));
+        self.offset.iter().for_each(|offset| offset.visit(f));
+    }
+
+    /// Writes the query into the sql command.
+    ///
+    /// The rows are limited with `LIMIT` and `OFFSET`, except for
+    /// [Dialect::MsSql] where `TOP` is used without an offset and
+    /// `OFFSET ... FETCH`, which requires an `ORDER BY`, with an offset.
+    pub fn render<Sql, Arg>(
+        &self,
+        sql: &mut Sql,
+        dialect: Dialect,
+    ) -> Result<(), RenderError<Arg::Error>>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+        T: Clone,
+    {
+        if self.distinct {
+            select_distinct(sql);
+        } else {
+            select(sql);
+        }
+
+        if dialect == Dialect::MsSql && self.offset.is_none() {
+            if let Some(count) = &self.limit {
+                separator_optional(sql);
+                sql.push_cmd("TOP");
+                let mut group = Group::open(sql);
+                count.render(group.sql(), dialect)?;
+            }
+        }
+
+        match self.columns.split_first() {
+            Some((first, rest)) => {
+                first.render(sql, dialect)?;
+                for item in rest {
+                    sql.push_cmd(",");
+                    item.render(sql, dialect)?;
+                }
+            }
+            None => column(sql, "*"),
+        }
+
+        if let Some((first, rest)) = self.from.split_first() {
+            from_tables(sql, []);
+            first.render(sql);
+            for table in rest {
+                sql.push_cmd(",");
+                table.render(sql);
+            }
+        }
+
+        for join_clause in &self.joins {
+            join(
+                sql,
+                join_clause.typ,
+                &join_clause.table.name,
+                join_clause.table.alias.as_deref(),
+            );
+            if let Some(on) = &join_clause.on {
+                join_on(sql);
+                on.render(sql, dialect)?;
+            }
+        }
+
+        if let Some(filter) = &self.filter {
+            filter_where(sql);
+            filter.render(sql, dialect)?;
+        }
+
+        if !self.group_by.is_empty() {
+            group_by(sql, []);
+            render_list(sql, dialect, &self.group_by)?;
+        }
+
+        if let Some(having) = &self.having {
+            filter_having(sql);
+            having.render(sql, dialect)?;
+        }
+
+        if let Some((first, rest)) = self.order_by.split_first() {
+            order_by(sql, []);
+            first.render(sql, dialect)?;
+            for order in rest {
+                sql.push_cmd(",");
+                order.render(sql, dialect)?;
+            }
+        }
+
+        self.render_rows(sql, dialect)
+    }
+
+    fn render_rows<Sql, Arg>(
+        &self,
+        sql: &mut Sql,
+        dialect: Dialect,
+    ) -> Result<(), RenderError<Arg::Error>>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+        T: Clone,
+    {
+        match dialect {
+            Dialect::Postgres | Dialect::MySql | Dialect::Sqlite => {
+                if let Some(count) = &self.limit {
+                    separator_optional(sql);
+                    sql.push_cmd("LIMIT");
+                    count.render(sql, dialect)?;
+                }
+                if let Some(start) = &self.offset {
+                    if self.limit.is_none() && dialect != Dialect::Postgres {
+                        return Err(DialectError::Unsupported("OFFSET without LIMIT").into());
+                    }
+                    separator_optional(sql);
+                    sql.push_cmd("OFFSET");
+                    start.render(sql, dialect)?;
+                }
+            }
+            Dialect::MsSql => {
+                // without an offset, the limit is written with TOP
+                let Some(start) = &self.offset else {
+                    return Ok(());
+                };
+                if self.order_by.is_empty() {
+                    return Err(RenderError::Incomplete("ORDER BY of OFFSET"));
+                }
+                separator_optional(sql);
+                sql.push_cmd("OFFSET");
+                start.render(sql, dialect)?;
+                sql.push_cmd(" ROWS");
+                if let Some(count) = &self.limit {
+                    sql.push_cmd(" FETCH NEXT");
+                    count.render(sql, dialect)?;
+                    sql.push_cmd(" ROWS ONLY");
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Renders the query into a new sql command.
+    pub fn to_command<Arg>(
+        &self,
+        dialect: Dialect,
+    ) -> Result<SqlCommand<Arg>, RenderError<Arg::Error>>
+    where
+        Arg: ArgumentBuffer<T> + Default,
+        T: Clone,
+    {
+        let mut sql = SqlCommand::default();
+        self.render(&mut sql, dialect)?;
+        Ok(sql)
+    }
+}
+
+impl<T> SelectItem<T> {
+    fn render<Sql, Arg>(
+        &self,
+        sql: &mut Sql,
+        dialect: Dialect,
+    ) -> Result<(), RenderError<Arg::Error>>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+        T: Clone,
+    {
+        self.expr.render(sql, dialect)?;
+        if let Some(alias) = &self.alias {
+            sql.push_cmd(" AS ");
+            sql.push_cmd(alias);
+        }
+        Ok(())
+    }
+}
+
+impl<T> OrderBy<T> {
+    fn render<Sql, Arg>(
+        &self,
+        sql: &mut Sql,
+        dialect: Dialect,
+    ) -> Result<(), RenderError<Arg::Error>>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+        T: Clone,
+    {
+        self.expr.render(sql, dialect)?;
+
+        match self.order {
+            OrderByOrd::Desc => sql.push_cmd(" DESC"),
+            OrderByOrd::Asc => sql.push_cmd(" ASC"),
+            OrderByOrd::Using(op) => {
+                sql.push_cmd(" USING ");
+                sql.push_cmd(op);
+            }
+            OrderByOrd::Default => {}
+        }
+
+        match self.nulls {
+            OrderByNulls::First => sql.push_cmd(" NULLS FIRST"),
+            OrderByNulls::Last => sql.push_cmd(" NULLS LAST"),
+            OrderByNulls::Default => {}
+        }
+        Ok(())
+    }
+}
+
+/// `INSERT` command
+///
+/// Without rows, a row of default values is inserted.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect};
+/// # use sqlstr::ast::{Expr, Insert, RenderError};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), RenderError<Infallible>> {
+/// let insert: Insert = Insert::into("user")
+///     .columns(["name", "email"])
+///     .row([Expr::value("foo".into()), Expr::value("foo@mail.com".into())])
+///     .returning(["id"]);
+///
+/// let sql: SqlCommand<Void> = insert.to_command(Dialect::Postgres)?;
+/// assert_eq!(
+///     sql.as_command(),
+///     "INSERT INTO user (name, email) VALUES ($1, $2) RETURNING id"
+/// );
+///
+/// let sql: SqlCommand<Void> = insert.to_command(Dialect::MsSql)?;
+/// assert_eq!(
+///     sql.as_command(),
+///     "INSERT INTO user (name, email) OUTPUT INSERTED.id VALUES ($1, $2)"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq)]
+pub struct Insert<T = Value> {
+    pub table: Cow<'static, str>,
+    pub columns: Vec<Cow<'static, str>>,
+    pub rows: Vec<Vec<Expr<T>>>,
+    /// Returned columns, all the columns when empty
+    pub returning: Option<Vec<Cow<'static, str>>>,
+}
+
+impl<T> Insert<T> {
+    pub fn into<Tbl>(table: Tbl) -> Self
+    where
+        Tbl: Into<Cow<'static, str>>,
+    {
+        Self {
+            table: table.into(),
+            columns: Vec::new(),
+            rows: Vec::new(),
+            returning: None,
+        }
+    }
+
+    pub fn columns<I, C>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<Cow<'static, str>>,
+    {
+        self.columns.extend(columns.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn row<I>(mut self, row: I) -> Self
+    where
+        I: IntoIterator<Item = Expr<T>>,
+    {
+        self.rows.push(row.into_iter().collect());
+        self
+    }
+
+    pub fn returning<I, C>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<Cow<'static, str>>,
+    {
+        self.returning = Some(columns.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Rewrites every expression of the command with [Expr::transform].
+    pub fn transform<F>(self, f: &mut F) -> Self
+    where
+        F: FnMut(Expr<T>) -> Expr<T>,
+    {
+        Self {
+            table: self.table,
+            columns: self.columns,
+            rows: self
+                .rows
+                .into_iter()
+                .map(|row| row.into_iter().map(|expr| expr.transform(f)).collect())
+                .collect(),
+            returning: self.returning,
+        }
+    }
+
+    /// Visits every expression of the command with [Expr::visit].
+    pub fn visit<F>(&self, f: &mut F)
+    where
+        F: FnMut(&Expr<T>),
+    {
+        self.rows.iter().flatten().for_each(|expr| expr.visit(f));
+    }
+
+    /// Writes the command into the sql command.
+    pub fn render<Sql, Arg>(
+        &self,
+        sql: &mut Sql,
+        dialect: Dialect,
+    ) -> Result<(), RenderError<Arg::Error>>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+        T: Clone,
+    {
+        let returning = self.returning.as_deref().map(returning_list);
+
+        insert_into(sql, &self.table);
+        if !self.columns.is_empty() {
+            let mut group = Group::open(sql);
+            columns_iter(group.sql(), self.columns.iter().map(AsRef::as_ref));
+        }
+        if let Some(columns) = &returning {
+            output_rows(sql, dialect, ReturningCommand::Insert, columns)?;
+        }
+
+        if self.rows.is_empty() {
+            separator_optional(sql);
+            sql.push_cmd(match dialect {
+                Dialect::MySql => "VALUES ()",
+                Dialect::Postgres | Dialect::Sqlite | Dialect::MsSql => "DEFAULT VALUES",
+            });
+        } else {
+            values(sql);
+            for (i, row) in self.rows.iter().enumerate() {
+                if i > 0 {
+                    sql.push_cmd(",");
+                }
+                let mut group = Group::open(sql);
+                render_list(group.sql(), dialect, row)?;
+            }
+        }
+
+        if let Some(columns) = &returning {
+            returning_rows(sql, dialect, columns)?;
+        }
+        Ok(())
+    }
+
+    /// Renders the command into a new sql command.
+    pub fn to_command<Arg>(
+        &self,
+        dialect: Dialect,
+    ) -> Result<SqlCommand<Arg>, RenderError<Arg::Error>>
+    where
+        Arg: ArgumentBuffer<T> + Default,
+        T: Clone,
+    {
+        let mut sql = SqlCommand::default();
+        self.render(&mut sql, dialect)?;
+        Ok(sql)
+    }
+}
+
+/// `UPDATE` command
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect};
+/// # use sqlstr::ast::{Expr, Update, RenderError};
+/// # use sqlstr::expr::{Cmp, math::MathBi};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), RenderError<Infallible>> {
+/// let update: Update = Update::table("product")
+///     .set("price", Expr::binary(Expr::column("price"), MathBi::Mult, Expr::value(2.into())))
+///     .filter(Expr::binary(Expr::column("id"), Cmp::Eq, Expr::value(7.into())));
+///
+/// let sql: SqlCommand<Void> = update.to_command(Dialect::Postgres)?;
+/// assert_eq!(sql.as_command(), "UPDATE product SET price = price * $1 WHERE id = $2");
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq)]
+pub struct Update<T = Value> {
+    pub table: Cow<'static, str>,
+    pub assignments: Vec<(Cow<'static, str>, Expr<T>)>,
+    pub filter: Option<Expr<T>>,
+    /// Returned columns, all the columns when empty
+    pub returning: Option<Vec<Cow<'static, str>>>,
+}
+
+impl<T> Update<T> {
+    pub fn table<Tbl>(table: Tbl) -> Self
+    where
+        Tbl: Into<Cow<'static, str>>,
+    {
+        Self {
+            table: table.into(),
+            assignments: Vec::new(),
+            filter: None,
+            returning: None,
+        }
+    }
+
+    pub fn set<C>(mut self, column: C, value: Expr<T>) -> Self
+    where
+        C: Into<Cow<'static, str>>,
+    {
+        self.assignments.push((column.into(), value));
+        self
+    }
+
+    /// Adds a `WHERE` condition, joined with `AND` to the previous ones.
+    pub fn filter(mut self, condition: Expr<T>) -> Self {
+        self.filter = Some(and_condition(self.filter.take(), condition));
+        self
+    }
+
+    pub fn returning<I, C>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<Cow<'static, str>>,
+    {
+        self.returning = Some(columns.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Rewrites every expression of the command with [Expr::transform].
+    pub fn transform<F>(self, f: &mut F) -> Self
+    where
+        F: FnMut(Expr<T>) -> Expr<T>,
+    {
+        Self {
+            table: self.table,
+            assignments: self
+                .assignments
+                .into_iter()
+                .map(|(column, value)| (column, value.transform(f)))
+                .collect(),
+            filter: self.filter.map(|filter| filter.transform(f)),
+            returning: self.returning,
+        }
+    }
+
+    /// Visits every expression of the command with [Expr::visit].
+    pub fn visit<F>(&self, f: &mut F)
+    where
+        F: FnMut(&Expr<T>),
+    {
+        self.assignments
+            .iter()
+            .for_each(|(_, value)| value.visit(f));
+        self.filter.iter().for_each(|filter| filter.visit(f));
+    }
+
+    /// Writes the command into the sql command.
+    pub fn render<Sql, Arg>(
+        &self,
+        sql: &mut Sql,
+        dialect: Dialect,
+    ) -> Result<(), RenderError<Arg::Error>>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+        T: Clone,
+    {
+        if self.assignments.is_empty() {
+            return Err(RenderError::Incomplete("SET of UPDATE"));
+        }
+        let returning = self.returning.as_deref().map(returning_list);
+
+        update_table(sql, &self.table);
+        set_update(sql);
+        for (i, (column, value)) in self.assignments.iter().enumerate() {
+            if i > 0 {
+                sql.push_cmd(",");
+            }
+            set_column(sql, column);
+            value.render(sql, dialect)?;
+        }
+        if let Some(columns) = &returning {
+            output_rows(sql, dialect, ReturningCommand::Update, columns)?;
+        }
+
+        if let Some(filter) = &self.filter {
+            filter_where(sql);
+            filter.render(sql, dialect)?;
+        }
+
+        if let Some(columns) = &returning {
+            returning_rows(sql, dialect, columns)?;
+        }
+        Ok(())
+    }
+
+    /// Renders the command into a new sql command.
+    pub fn to_command<Arg>(
+        &self,
+        dialect: Dialect,
+    ) -> Result<SqlCommand<Arg>, RenderError<Arg::Error>>
+    where
+        Arg: ArgumentBuffer<T> + Default,
+        T: Clone,
+    {
+        let mut sql = SqlCommand::default();
+        self.render(&mut sql, dialect)?;
+        Ok(sql)
+    }
+}
+
+/// `DELETE` command
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect};
+/// # use sqlstr::ast::{Expr, Delete, RenderError};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), RenderError<Infallible>> {
+/// let delete: Delete = Delete::from("session")
+///     .filter(Expr::column("expired").is_not_null())
+///     .returning(["id"]);
+///
+/// let sql: SqlCommand<Void> = delete.to_command(Dialect::Sqlite)?;
+/// assert_eq!(
+///     sql.as_command(),
+///     "DELETE FROM session WHERE expired IS NOT NULL RETURNING id"
+/// );
+///
+/// let sql: SqlCommand<Void> = delete.to_command(Dialect::MsSql)?;
+/// assert_eq!(
+///     sql.as_command(),
+///     "DELETE FROM session OUTPUT DELETED.id WHERE expired IS NOT NULL"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq)]
+pub struct Delete<T = Value> {
+    pub table: Cow<'static, str>,
+    pub filter: Option<Expr<T>>,
+    /// Returned columns, all the columns when empty
+    pub returning: Option<Vec<Cow<'static, str>>>,
+}
+
+impl<T> Delete<T> {
+    pub fn from<Tbl>(table: Tbl) -> Self
+    where
+        Tbl: Into<Cow<'static, str>>,
+    {
+        Self {
+            table: table.into(),
+            filter: None,
+            returning: None,
+        }
+    }
+
+    /// Adds a `WHERE` condition, joined with `AND` to the previous ones.
+    pub fn filter(mut self, condition: Expr<T>) -> Self {
+        self.filter = Some(and_condition(self.filter.take(), condition));
+        self
+    }
+
+    pub fn returning<I, C>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<Cow<'static, str>>,
+    {
+        self.returning = Some(columns.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Rewrites every expression of the command with [Expr::transform].
+    pub fn transform<F>(self, f: &mut F) -> Self
+    where
+        F: FnMut(Expr<T>) -> Expr<T>,
+    {
+        Self {
+            table: self.table,
+            filter: self.filter.map(|filter| filter.transform(f)),
+            returning: self.returning,
+        }
+    }
+
+    /// Visits every expression of the command with [Expr::visit].
+    pub fn visit<F>(&self, f: &mut F)
+    where
+        F: FnMut(&Expr<T>),
+    {
+        self.filter.iter().for_each(|filter| filter.visit(f));
+    }
+
+    /// Writes the command into the sql command.
+    pub fn render<Sql, Arg>(
+        &self,
+        sql: &mut Sql,
+        dialect: Dialect,
+    ) -> Result<(), RenderError<Arg::Error>>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+        T: Clone,
+    {
+        let returning = self.returning.as_deref().map(returning_list);
+
+        delete_from(sql, &self.table);
+        if let Some(columns) = &returning {
+            output_rows(sql, dialect, ReturningCommand::Delete, columns)?;
+        }
+
+        if let Some(filter) = &self.filter {
+            filter_where(sql);
+            filter.render(sql, dialect)?;
+        }
+
+        if let Some(columns) = &returning {
+            returning_rows(sql, dialect, columns)?;
+        }
+        Ok(())
+    }
+
+    /// Renders the command into a new sql command.
+    pub fn to_command<Arg>(
+        &self,
+        dialect: Dialect,
+    ) -> Result<SqlCommand<Arg>, RenderError<Arg::Error>>
+    where
+        Arg: ArgumentBuffer<T> + Default,
+        T: Clone,
+    {
+        let mut sql = SqlCommand::default();
+        self.render(&mut sql, dialect)?;
+        Ok(sql)
+    }
+}
+
+/// Owned command
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq)]
+pub enum Statement<T = Value> {
+    Select(Select<T>),
+    Insert(Insert<T>),
+    Update(Update<T>),
+    Delete(Delete<T>),
+}
+
+impl<T> Statement<T> {
+    /// Rewrites every expression of the command with [Expr::transform].
+    pub fn transform<F>(self, f: &mut F) -> Self
+    where
+        F: FnMut(Expr<T>) -> Expr<T>,
+    {
+        match self {
+            Self::Select(select) => Self::Select(select.transform(f)),
+            Self::Insert(insert) => Self::Insert(insert.transform(f)),
+            Self::Update(update) => Self::Update(update.transform(f)),
+            Self::Delete(delete) => Self::Delete(delete.transform(f)),
+        }
+    }
+
+    /// Visits every expression of the command with [Expr::visit].
+    pub fn visit<F>(&self, f: &mut F)
+    where
+        F: FnMut(&Expr<T>),
+    {
+        match self {
+            Self::Select(select) => select.visit(f),
+            Self::Insert(insert) => insert.visit(f),
+            Self::Update(update) => update.visit(f),
+            Self::Delete(delete) => delete.visit(f),
+        }
+    }
+
+    /// Writes the command into the sql command.
+    pub fn render<Sql, Arg>(
+        &self,
+        sql: &mut Sql,
+        dialect: Dialect,
+    ) -> Result<(), RenderError<Arg::Error>>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+        T: Clone,
+    {
+        match self {
+            Self::Select(select) => select.render(sql, dialect),
+            Self::Insert(insert) => insert.render(sql, dialect),
+            Self::Update(update) => update.render(sql, dialect),
+            Self::Delete(delete) => delete.render(sql, dialect),
+        }
+    }
+
+    /// Renders the command into a new sql command.
+    pub fn to_command<Arg>(
+        &self,
+        dialect: Dialect,
+    ) -> Result<SqlCommand<Arg>, RenderError<Arg::Error>>
+    where
+        Arg: ArgumentBuffer<T> + Default,
+        T: Clone,
+    {
+        let mut sql = SqlCommand::default();
+        self.render(&mut sql, dialect)?;
+        Ok(sql)
+    }
+}
+
+impl<T> From<Select<T>> for Statement<T> {
+    fn from(value: Select<T>) -> Self {
+        Self::Select(value)
+    }
+}
+
+impl<T> From<Insert<T>> for Statement<T> {
+    fn from(value: Insert<T>) -> Self {
+        Self::Insert(value)
+    }
+}
+
+impl<T> From<Update<T>> for Statement<T> {
+    fn from(value: Update<T>) -> Self {
+        Self::Update(value)
+    }
+}
+
+impl<T> From<Delete<T>> for Statement<T> {
+    fn from(value: Delete<T>) -> Self {
+        Self::Delete(value)
+    }
+}
+
+fn and_condition<T>(previous: Option<Expr<T>>, condition: Expr<T>) -> Expr<T> {
+    match previous {
+        Some(previous) => Expr::binary(previous, LogicBi::And, condition),
+        None => condition,
+    }
+}
+
+fn returning_list<'c>(columns: &'c [Cow<'static, str>]) -> ColumnExprList<'c> {
+    columns
+        .iter()
+        .fold(column_list(), |list, column| list.column(column.as_ref()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{expr::Cmp, test::TestArgs};
+
+    #[test]
+    fn subquery_filter() {
+        let posts: Select = Select::new()
+            .column(Expr::raw("1"))
+            .from("post")
+            .filter(Expr::binary(
+                Expr::column("post.user_id"),
+                Cmp::Eq,
+                Expr::column("user.id"),
+            ));
+        let query: Select = Select::new()
+            .distinct()
+            .column(Expr::column("name"))
+            .from("user")
+            .cross_join(TableRef::new("config").alias("c"))
+            .filter(Expr::exists(posts))
+            .filter(Expr::binary(
+                Expr::column("age"),
+                Cmp::Gte,
+                Expr::subquery(
+                    Select::new()
+                        .column(Expr::column("c.min_age"))
+                        .from("config"),
+                ),
+            ))
+            .limit(Expr::value(5.into()));
+
+        let sql: SqlCommand<TestArgs> = query.to_command(Dialect::MsSql).unwrap();
+        assert_eq!(
+            sql.as_command(),
+            "SELECT DISTINCT TOP ($1) name FROM user CROSS JOIN config AS c \
+             WHERE EXISTS (SELECT 1 FROM post WHERE post.user_id = user.id) \
+             AND age >= (SELECT c.min_age FROM config)"
+        );
+        assert_eq!(sql.arguments.as_str(), "5;");
+    }
+
+    #[test]
+    fn transform_statement() {
+        let statement: Statement = Update::table("user")
+            .set("name", Expr::value("foo".into()))
+            .filter(Expr::binary(
+                Expr::column("id"),
+                Cmp::Eq,
+                Expr::value(1.into()),
+            ))
+            .into();
+        let statement = statement.transform(&mut |expr| match expr {
+            Expr::Value(Value::Text(name)) => Expr::function("upper", [Expr::value(name.into())]),
+            expr => expr,
+        });
+
+        let sql: SqlCommand<TestArgs> = statement.to_command(Dialect::Postgres).unwrap();
+        assert_eq!(
+            sql.as_command(),
+            "UPDATE user SET name = upper($1) WHERE id = $2"
+        );
+        assert_eq!(sql.arguments.as_str(), "foo;1;");
+    }
+
+    #[test]
+    fn dialect_errors() {
+        let insert: Insert = Insert::into("user").returning(Vec::<&str>::new());
+        let sql: SqlCommand<TestArgs> = insert.to_command(Dialect::Sqlite).unwrap();
+        assert_eq!(
+            sql.as_command(),
+            "INSERT INTO user DEFAULT VALUES RETURNING *"
+        );
+        assert_eq!(
+            insert.to_command::<TestArgs>(Dialect::MySql),
+            Err(RenderError::Dialect(DialectError::Unsupported("RETURNING")))
+        );
+
+        let query: Select = Select::new().from("user").offset(Expr::value(10.into()));
+        assert_eq!(
+            query.to_command::<TestArgs>(Dialect::Sqlite),
+            Err(RenderError::Dialect(DialectError::Unsupported(
+                "OFFSET without LIMIT"
+            )))
+        );
+        assert_eq!(
+            query.to_command::<TestArgs>(Dialect::MsSql),
+            Err(RenderError::Incomplete("ORDER BY of OFFSET"))
+        );
+        let sql: SqlCommand<TestArgs> = query.to_command(Dialect::Postgres).unwrap();
+        assert_eq!(sql.as_command(), "SELECT * FROM user OFFSET $1");
+
+        assert_eq!(
+            Update::<Value>::table("user").to_command::<TestArgs>(Dialect::Postgres),
+            Err(RenderError::Incomplete("SET of UPDATE"))
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use alloc::{string::String, vec::Vec};
+
+/// Owned value of an expression tree, bound as an argument when rendered.
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+macro_rules! value_from {
+    ($variant:ident: $($ty:ty),+) => {
+        $(
+            impl From<$ty> for Value {
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value.into())
+                }
+            }
+        )+
+    };
+}
+
+value_from!(Bool: bool);
+value_from!(Int: i8, i16, i32, i64, u8, u16, u32);
+value_from!(Float: f32, f64);
+value_from!(Text: &str, String);
+value_from!(Bytes: &[u8], Vec<u8>);
+
+impl<T> From<Option<T>> for Value
+where
+    T: Into<Value>,
+{
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Null, Into::into)
+    }
+}
+
+#[cfg(test)]
+impl core::fmt::Display for Value {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Null => f.write_str("NULL"),
+            Self::Bool(value) => value.fmt(f),
+            Self::Int(value) => value.fmt(f),
+            Self::Float(value) => value.fmt(f),
+            Self::Text(value) => f.write_str(value),
+            Self::Bytes(value) => {
+                f.write_str("\\x")?;
+                value.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Value;
+
+    #[test]
+    fn value_conversion() {
+        assert_eq!(Value::from(7_u8), Value::Int(7));
+        assert_eq!(Value::from("foo"), Value::Text("foo".into()));
+        assert_eq!(Value::from(None::<i32>), Value::Null);
+        assert_eq!(Value::from(Some(true)), Value::Bool(true));
+    }
+}
@@ -0,0 +1,448 @@
+//! PostgreSQL `COPY` commands.
+//!
+//! With the `std` feature, rows can be encoded in the binary format of
+//! `COPY ... FROM STDIN (FORMAT binary)` with `BinaryCopyWriter`.
+
+#[cfg(feature = "std")]
+mod binary;
+
+#[cfg(feature = "std")]
+pub use binary::*;
+
+use alloc::vec::Vec;
+
+use crate::{
+    expr::{quote_literal, separator_optional},
+    placeholder::Placeholders,
+    Dialect, DialectError, WriteSql,
+};
+
+/// Error while writing a `COPY` command.
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CopyError {
+    /// The feature is not supported by the dialect.
+    Dialect(DialectError),
+    /// The option is not accepted in the direction of the copy.
+    Direction(&'static str),
+    /// The query has `$N` placeholders, `COPY` does not accept arguments.
+    Placeholder,
+}
+
+#[cfg(feature = "fmt")]
+impl core::fmt::Display for CopyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Dialect(err) => err.fmt(f),
+            Self::Direction(option) => {
+                write!(f, "{option} is not accepted in this direction of COPY")
+            }
+            Self::Placeholder => f.write_str("the query of a COPY can not have placeholders"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CopyError {}
+
+impl From<DialectError> for CopyError {
+    fn from(value: DialectError) -> Self {
+        Self::Dialect(value)
+    }
+}
+
+/// Data format of the copy
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CopyFormat {
+    Text,
+    Csv,
+    Binary,
+}
+
+impl CopyFormat {
+    pub const fn as_str(&self) -> &'static str {
+        match *self {
+            Self::Text => "text",
+            Self::Csv => "csv",
+            Self::Binary => "binary",
+        }
+    }
+}
+
+/// Columns always quoted in a CSV `COPY TO`
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub enum ForceQuote<'c> {
+    All,
+    Columns(Vec<&'c str>),
+}
+
+/// Options of the `COPY` command
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct CopyOptions<'c> {
+    pub format: Option<CopyFormat>,
+    pub header: bool,
+    pub delimiter: Option<char>,
+    pub null: Option<&'c str>,
+    pub quote: Option<char>,
+    /// Only accepted by `COPY TO`
+    pub force_quote: Option<ForceQuote<'c>>,
+    /// Only accepted by `COPY FROM`
+    pub freeze: bool,
+}
+
+impl<'c> CopyOptions<'c> {
+    pub const fn new() -> Self {
+        Self {
+            format: None,
+            header: false,
+            delimiter: None,
+            null: None,
+            quote: None,
+            force_quote: None,
+            freeze: false,
+        }
+    }
+
+    pub fn format(mut self, format: CopyFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    pub fn header(mut self) -> Self {
+        self.header = true;
+        self
+    }
+
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
+    /// String representing a null value.
+    pub fn null(mut self, null: &'c str) -> Self {
+        self.null = Some(null);
+        self
+    }
+
+    pub fn quote(mut self, quote: char) -> Self {
+        self.quote = Some(quote);
+        self
+    }
+
+    pub fn force_quote(mut self, force_quote: ForceQuote<'c>) -> Self {
+        self.force_quote = Some(force_quote);
+        self
+    }
+
+    pub fn freeze(mut self) -> Self {
+        self.freeze = true;
+        self
+    }
+}
+
+/// Source of a `COPY TO`
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CopySource<'c> {
+    /// Table with the copied columns, all columns when empty
+    Table(&'c str, &'c [&'c str]),
+    /// Built query, which can not have placeholders since `COPY` does not
+    /// accept arguments, see [CopyError::Placeholder]
+    Query(&'c str),
+}
+
+fn char_literal<Sql, Arg>(sql: &mut Sql, value: char)
+where
+    Sql: WriteSql<Arg>,
+{
+    let mut buf = [0; 4];
+    quote_literal(sql, value.encode_utf8(&mut buf));
+}
+
+fn column_list<Sql, Arg>(sql: &mut Sql, columns: &[&str])
+where
+    Sql: WriteSql<Arg>,
+{
+    if let Some((first, rest)) = columns.split_first() {
+        sql.push_cmd(" (");
+        sql.push_cmd(first);
+        for column in rest {
+            sql.push_cmd(", ");
+            sql.push_cmd(column);
+        }
+        sql.push_cmd(")");
+    }
+}
+
+fn copy_options<Sql, Arg>(sql: &mut Sql, options: &CopyOptions<'_>)
+where
+    Sql: WriteSql<Arg>,
+{
+    let mut first = true;
+    let mut item = |sql: &mut Sql, name: &str| {
+        sql.push_cmd(if first { " WITH (" } else { ", " });
+        sql.push_cmd(name);
+        first = false;
+    };
+
+    if let Some(format) = options.format {
+        item(sql, "FORMAT ");
+        sql.push_cmd(format.as_str());
+    }
+    if options.freeze {
+        item(sql, "FREEZE");
+    }
+    if let Some(delimiter) = options.delimiter {
+        item(sql, "DELIMITER ");
+        char_literal(sql, delimiter);
+    }
+    if let Some(null) = options.null {
+        item(sql, "NULL ");
+        quote_literal(sql, null);
+    }
+    if options.header {
+        item(sql, "HEADER");
+    }
+    if let Some(quote) = options.quote {
+        item(sql, "QUOTE ");
+        char_literal(sql, quote);
+    }
+    match &options.force_quote {
+        Some(ForceQuote::All) => item(sql, "FORCE_QUOTE *"),
+        Some(ForceQuote::Columns(columns)) => {
+            item(sql, "FORCE_QUOTE");
+            column_list(sql, columns);
+        }
+        None => {}
+    }
+
+    if !first {
+        sql.push_cmd(")");
+    }
+}
+
+/// Writes a `COPY <table> FROM STDIN` command.
+///
+/// `FORCE_QUOTE` is only accepted by `COPY TO`, it returns
+/// [CopyError::Direction].
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void};
+/// # use sqlstr::copy::{copy_from, CopyOptions, CopyFormat, CopyError};
+/// # fn main() -> Result<(), CopyError> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// copy_from(
+///     &mut sql,
+///     "user",
+///     &["id", "name"],
+///     &CopyOptions::new().format(CopyFormat::Csv).header().delimiter(';'),
+/// )?;
+///
+/// assert_eq!(
+///     sql.as_command(),
+///     "COPY user (id, name) FROM STDIN WITH (FORMAT csv, DELIMITER ';', HEADER)"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn copy_from<Sql, Arg>(
+    sql: &mut Sql,
+    table: &str,
+    columns: &[&str],
+    options: &CopyOptions<'_>,
+) -> Result<(), CopyError>
+where
+    Sql: WriteSql<Arg>,
+{
+    if options.force_quote.is_some() {
+        return Err(CopyError::Direction("FORCE_QUOTE"));
+    }
+
+    separator_optional(sql);
+    sql.push_cmd("COPY ");
+    sql.push_cmd(table);
+    column_list(sql, columns);
+    sql.push_cmd(" FROM STDIN");
+    copy_options(sql, options);
+
+    Ok(())
+}
+
+/// Writes a `COPY ... TO STDOUT` command.
+///
+/// A [CopySource::Query] with `$N` placeholders returns
+/// [CopyError::Placeholder], the arguments of a built query can not be
+/// passed to the copy. `FREEZE` is only accepted by `COPY FROM`, it returns
+/// [CopyError::Direction].
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void};
+/// # use sqlstr::copy::{copy_to, CopySource, CopyOptions, CopyFormat, ForceQuote, CopyError};
+/// # fn main() -> Result<(), CopyError> {
+/// let mut query: SqlCommand<Void> = SqlCommand::default();
+/// query.push_cmd("SELECT id, name FROM user WHERE active");
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// copy_to(
+///     &mut sql,
+///     CopySource::Query(query.as_command()),
+///     &CopyOptions::new()
+///         .format(CopyFormat::Csv)
+///         .null("")
+///         .force_quote(ForceQuote::All),
+/// )?;
+///
+/// assert_eq!(
+///     sql.as_command(),
+///     "COPY (SELECT id, name FROM user WHERE active) TO STDOUT \
+///     WITH (FORMAT csv, NULL '', FORCE_QUOTE *)"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn copy_to<Sql, Arg>(
+    sql: &mut Sql,
+    source: CopySource<'_>,
+    options: &CopyOptions<'_>,
+) -> Result<(), CopyError>
+where
+    Sql: WriteSql<Arg>,
+{
+    if options.freeze {
+        return Err(CopyError::Direction("FREEZE"));
+    }
+    if let CopySource::Query(query) = source {
+        if Placeholders::new(query, Dialect::Postgres).next().is_some() {
+            return Err(CopyError::Placeholder);
+        }
+    }
+
+    separator_optional(sql);
+    sql.push_cmd("COPY ");
+    match source {
+        CopySource::Table(table, columns) => {
+            sql.push_cmd(table);
+            column_list(sql, columns);
+        }
+        CopySource::Query(query) => {
+            sql.push_cmd("(");
+            sql.push_cmd(query);
+            sql.push_cmd(")");
+        }
+    }
+    sql.push_cmd(" TO STDOUT");
+    copy_options(sql, options);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::TestArgs, SqlCommand};
+
+    #[test]
+    fn copy_from_options() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        copy_from(
+            &mut sql,
+            "event",
+            &[],
+            &CopyOptions::new()
+                .format(CopyFormat::Text)
+                .freeze()
+                .null("it's null")
+                .quote('\''),
+        )
+        .unwrap();
+
+        assert_eq!(
+            sql.as_command(),
+            "COPY event FROM STDIN WITH (FORMAT text, FREEZE, NULL 'it''s null', QUOTE '''')"
+        );
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        assert_eq!(
+            copy_from(
+                &mut sql,
+                "event",
+                &[],
+                &CopyOptions::new().force_quote(ForceQuote::All)
+            ),
+            Err(CopyError::Direction("FORCE_QUOTE"))
+        );
+    }
+
+    #[test]
+    fn copy_to_table() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        copy_to(
+            &mut sql,
+            CopySource::Table("user", &["id", "name"]),
+            &CopyOptions::new()
+                .format(CopyFormat::Csv)
+                .header()
+                .force_quote(ForceQuote::Columns(alloc::vec!["name"])),
+        )
+        .unwrap();
+
+        assert_eq!(
+            sql.as_command(),
+            "COPY user (id, name) TO STDOUT WITH (FORMAT csv, HEADER, FORCE_QUOTE (name))"
+        );
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        copy_to(
+            &mut sql,
+            CopySource::Table("user", &[]),
+            &CopyOptions::new(),
+        )
+        .unwrap();
+        assert_eq!(sql.as_command(), "COPY user TO STDOUT");
+
+        assert_eq!(
+            copy_to(
+                &mut sql,
+                CopySource::Table("user", &[]),
+                &CopyOptions::new().freeze()
+            ),
+            Err(CopyError::Direction("FREEZE"))
+        );
+    }
+
+    #[test]
+    fn copy_to_query_placeholders() {
+        let mut query: SqlCommand<TestArgs> = SqlCommand::default();
+        query.push_cmd("SELECT id FROM user WHERE name = '$1' AND age > ");
+        query.push_value(18).unwrap();
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        assert_eq!(
+            copy_to(
+                &mut sql,
+                CopySource::Query(query.as_command()),
+                &CopyOptions::new()
+            ),
+            Err(CopyError::Placeholder)
+        );
+        assert_eq!(sql.as_command(), "");
+
+        copy_to(
+            &mut sql,
+            CopySource::Query("SELECT id FROM user WHERE name = '$1'"),
+            &CopyOptions::new(),
+        )
+        .unwrap();
+        assert_eq!(
+            sql.as_command(),
+            "COPY (SELECT id FROM user WHERE name = '$1') TO STDOUT"
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use alloc::{string::String, vec::Vec};
+use std::io::{self, Write};
+
+/// Signature, flags and header extension length of the binary format
+const HEADER: &[u8] = b"PGCOPY\n\xff\r\n\0\0\0\0\0\0\0\0\0";
+
+/// Value encoded in the binary `COPY` format
+///
+/// The encoding must match the binary representation of the column type,
+/// like `int8` for [i64] or `bytea` for byte slices.
+pub trait BinaryField {
+    /// Appends the value, without the length prefix.
+    fn encode_binary(&self, buf: &mut Vec<u8>);
+
+    fn is_null(&self) -> bool {
+        false
+    }
+}
+
+macro_rules! be_field {
+    ($($ty:ty),*) => {
+        $(
+            impl BinaryField for $ty {
+                fn encode_binary(&self, buf: &mut Vec<u8>) {
+                    buf.extend_from_slice(&self.to_be_bytes());
+                }
+            }
+        )*
+    };
+}
+
+be_field!(i16, i32, i64, f32, f64);
+
+impl BinaryField for bool {
+    fn encode_binary(&self, buf: &mut Vec<u8>) {
+        buf.push(u8::from(*self));
+    }
+}
+
+impl BinaryField for str {
+    fn encode_binary(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl BinaryField for String {
+    fn encode_binary(&self, buf: &mut Vec<u8>) {
+        self.as_str().encode_binary(buf);
+    }
+}
+
+impl BinaryField for [u8] {
+    fn encode_binary(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self);
+    }
+}
+
+impl BinaryField for Vec<u8> {
+    fn encode_binary(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self);
+    }
+}
+
+impl<T> BinaryField for &T
+where
+    T: BinaryField + ?Sized,
+{
+    fn encode_binary(&self, buf: &mut Vec<u8>) {
+        (**self).encode_binary(buf);
+    }
+
+    fn is_null(&self) -> bool {
+        (**self).is_null()
+    }
+}
+
+impl<T> BinaryField for Option<T>
+where
+    T: BinaryField,
+{
+    fn encode_binary(&self, buf: &mut Vec<u8>) {
+        if let Some(value) = self {
+            value.encode_binary(buf);
+        }
+    }
+
+    fn is_null(&self) -> bool {
+        self.as_ref().map_or(true, T::is_null)
+    }
+}
+
+/// Encoder of rows in the binary format of `COPY ... FROM STDIN (FORMAT binary)`
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::copy::BinaryCopyWriter;
+/// # fn main() -> std::io::Result<()> {
+/// let mut writer = BinaryCopyWriter::new(Vec::new())?;
+/// writer.write_row(&[&1_i32, &"foo", &None::<i64>])?;
+/// let data = writer.finish()?;
+///
+/// assert!(data.starts_with(b"PGCOPY\n\xff\r\n\0"));
+/// assert!(data.ends_with(&[0xff, 0xff]));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BinaryCopyWriter<W> {
+    writer: W,
+    buf: Vec<u8>,
+}
+
+impl<W> BinaryCopyWriter<W>
+where
+    W: Write,
+{
+    /// Writes the header of the format.
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        writer.write_all(HEADER)?;
+        Ok(Self {
+            writer,
+            buf: Vec::new(),
+        })
+    }
+
+    /// Writes a row with the fields in the order of the copied columns.
+    pub fn write_row(&mut self, fields: &[&dyn BinaryField]) -> io::Result<()> {
+        let count = i16::try_from(fields.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "too many fields"))?;
+
+        self.buf.clear();
+        self.buf.extend_from_slice(&count.to_be_bytes());
+        for field in fields {
+            if field.is_null() {
+                self.buf.extend_from_slice(&(-1_i32).to_be_bytes());
+                continue;
+            }
+
+            let start = self.buf.len();
+            self.buf.extend_from_slice(&[0; 4]);
+            field.encode_binary(&mut self.buf);
+            let len = i32::try_from(self.buf.len() - start - 4)
+                .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "field too large"))?;
+            self.buf[start..start + 4].copy_from_slice(&len.to_be_bytes());
+        }
+
+        self.writer.write_all(&self.buf)
+    }
+
+    /// Writes the trailer of the format and returns the inner writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.write_all(&(-1_i16).to_be_bytes())?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn binary_rows() {
+        let mut writer = BinaryCopyWriter::new(Vec::new()).unwrap();
+        writer
+            .write_row(&[&7_i64, &Some(true), &None::<String>, &&[0xab_u8, 0xcd][..]])
+            .unwrap();
+        let data = writer.finish().unwrap();
+
+        let mut expected = HEADER.to_vec();
+        expected.extend_from_slice(&[0, 4]);
+        expected.extend_from_slice(&[0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 7]);
+        expected.extend_from_slice(&[0, 0, 0, 1, 1]);
+        expected.extend_from_slice(&[0xff, 0xff, 0xff, 0xff]);
+        expected.extend_from_slice(&[0, 0, 0, 2, 0xab, 0xcd]);
+        expected.extend_from_slice(&[0xff, 0xff]);
+
+        assert_eq!(data, expected);
+    }
+}
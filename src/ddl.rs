@@ -0,0 +1,13 @@
+//! Data definition commands.
+
+mod alter_table;
+mod create_table;
+mod data_type;
+mod drop;
+mod index;
+
+pub use alter_table::*;
+pub use create_table::*;
+pub use data_type::*;
+pub use drop::*;
+pub use index::*;
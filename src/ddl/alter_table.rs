@@ -0,0 +1,603 @@
+use alloc::vec::Vec;
+
+use super::{
+    check_column, column_def, data_type, table_constraint, ColumnDef, DataType, TableConstraint,
+};
+use crate::{expr::separator_optional, Dialect, DialectError, WriteSql};
+
+/// Action of an `ALTER TABLE` command
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub enum AlterAction<'t> {
+    AddColumn(ColumnDef<'t>),
+    DropColumn {
+        column: &'t str,
+        if_exists: bool,
+    },
+    RenameColumn {
+        from: &'t str,
+        to: &'t str,
+    },
+    AlterColumnType {
+        column: &'t str,
+        data_type: DataType<'t>,
+        using: Option<&'t str>,
+    },
+    SetDefault {
+        column: &'t str,
+        expr: &'t str,
+    },
+    DropDefault(&'t str),
+    SetNotNull(&'t str),
+    DropNotNull(&'t str),
+    AddConstraint {
+        constraint: TableConstraint<'t>,
+        not_valid: bool,
+    },
+    DropConstraint(&'t str),
+    ValidateConstraint(&'t str),
+    RenameTo(&'t str),
+    SetSchema(&'t str),
+    /// Attaches a partition with its bound specification, written as is.
+    AttachPartition {
+        partition: &'t str,
+        bound: &'t str,
+    },
+    DetachPartition(&'t str),
+}
+
+impl AlterAction<'_> {
+    /// Whether the action must be the only one of its `ALTER TABLE` command.
+    fn standalone(&self, dialect: Dialect) -> bool {
+        match dialect {
+            Dialect::Postgres => matches!(
+                self,
+                Self::RenameColumn { .. }
+                    | Self::RenameTo(_)
+                    | Self::SetSchema(_)
+                    | Self::AttachPartition { .. }
+                    | Self::DetachPartition(_)
+            ),
+            Dialect::MySql => false,
+            Dialect::Sqlite | Dialect::MsSql => true,
+        }
+    }
+}
+
+/// `ALTER TABLE` command builder
+///
+/// The command is written with [alter_table].
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct AlterTable<'t> {
+    pub name: &'t str,
+    pub actions: Vec<AlterAction<'t>>,
+}
+
+impl<'t> AlterTable<'t> {
+    pub const fn new(name: &'t str) -> Self {
+        Self {
+            name,
+            actions: Vec::new(),
+        }
+    }
+
+    pub fn action(mut self, action: AlterAction<'t>) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    pub fn add_column(self, column: ColumnDef<'t>) -> Self {
+        self.action(AlterAction::AddColumn(column))
+    }
+
+    pub fn drop_column(self, column: &'t str) -> Self {
+        self.action(AlterAction::DropColumn {
+            column,
+            if_exists: false,
+        })
+    }
+
+    pub fn drop_column_if_exists(self, column: &'t str) -> Self {
+        self.action(AlterAction::DropColumn {
+            column,
+            if_exists: true,
+        })
+    }
+
+    pub fn rename_column(self, from: &'t str, to: &'t str) -> Self {
+        self.action(AlterAction::RenameColumn { from, to })
+    }
+
+    /// Changes the type of a column, converting the values with the `using`
+    /// expression when present.
+    pub fn alter_column_type(
+        self,
+        column: &'t str,
+        data_type: DataType<'t>,
+        using: Option<&'t str>,
+    ) -> Self {
+        self.action(AlterAction::AlterColumnType {
+            column,
+            data_type,
+            using,
+        })
+    }
+
+    pub fn set_default(self, column: &'t str, expr: &'t str) -> Self {
+        self.action(AlterAction::SetDefault { column, expr })
+    }
+
+    pub fn drop_default(self, column: &'t str) -> Self {
+        self.action(AlterAction::DropDefault(column))
+    }
+
+    pub fn set_not_null(self, column: &'t str) -> Self {
+        self.action(AlterAction::SetNotNull(column))
+    }
+
+    pub fn drop_not_null(self, column: &'t str) -> Self {
+        self.action(AlterAction::DropNotNull(column))
+    }
+
+    pub fn add_constraint(self, constraint: TableConstraint<'t>) -> Self {
+        self.action(AlterAction::AddConstraint {
+            constraint,
+            not_valid: false,
+        })
+    }
+
+    /// Adds a constraint without checking the existing rows, see
+    /// [validate_constraint](Self::validate_constraint).
+    pub fn add_constraint_not_valid(self, constraint: TableConstraint<'t>) -> Self {
+        self.action(AlterAction::AddConstraint {
+            constraint,
+            not_valid: true,
+        })
+    }
+
+    pub fn drop_constraint(self, name: &'t str) -> Self {
+        self.action(AlterAction::DropConstraint(name))
+    }
+
+    pub fn validate_constraint(self, name: &'t str) -> Self {
+        self.action(AlterAction::ValidateConstraint(name))
+    }
+
+    pub fn rename_to(self, name: &'t str) -> Self {
+        self.action(AlterAction::RenameTo(name))
+    }
+
+    pub fn set_schema(self, schema: &'t str) -> Self {
+        self.action(AlterAction::SetSchema(schema))
+    }
+
+    pub fn attach_partition(self, partition: &'t str, bound: &'t str) -> Self {
+        self.action(AlterAction::AttachPartition { partition, bound })
+    }
+
+    pub fn detach_partition(self, partition: &'t str) -> Self {
+        self.action(AlterAction::DetachPartition(partition))
+    }
+}
+
+fn check_action(dialect: Dialect, action: &AlterAction<'_>) -> Result<(), DialectError> {
+    if let AlterAction::AddColumn(column) = action {
+        check_column(dialect, column)?;
+        if dialect == Dialect::MySql && column.references.is_some() {
+            return Err(DialectError::Unsupported(
+                "REFERENCES in a column definition",
+            ));
+        }
+    }
+
+    let unsupported = match (dialect, action) {
+        (Dialect::Postgres, _) => None,
+        (
+            _,
+            AlterAction::AddConstraint {
+                not_valid: true, ..
+            },
+        ) => Some("NOT VALID"),
+        (_, AlterAction::ValidateConstraint(_)) => Some("VALIDATE CONSTRAINT"),
+        (_, AlterAction::SetSchema(_)) => Some("SET SCHEMA"),
+        (_, AlterAction::AttachPartition { .. }) => Some("ATTACH PARTITION"),
+        (_, AlterAction::DetachPartition(_)) => Some("DETACH PARTITION"),
+        (Dialect::MySql | Dialect::MsSql, AlterAction::AlterColumnType { using: Some(_), .. }) => {
+            Some("USING")
+        }
+        (
+            Dialect::MySql | Dialect::MsSql,
+            AlterAction::SetNotNull(_) | AlterAction::DropNotNull(_),
+        ) => Some("changing the nullability without the column type"),
+        (Dialect::MsSql, AlterAction::SetDefault { .. } | AlterAction::DropDefault(_)) => {
+            Some("changing the default without a constraint")
+        }
+        (Dialect::MsSql, AlterAction::RenameColumn { .. } | AlterAction::RenameTo(_)) => {
+            Some("renaming with ALTER TABLE")
+        }
+        // https://www.sqlite.org/lang_altertable.html, only MariaDB accepts it
+        // in MySQL
+        (
+            Dialect::Sqlite | Dialect::MySql,
+            AlterAction::DropColumn {
+                if_exists: true, ..
+            },
+        ) => Some("IF EXISTS"),
+        (
+            Dialect::Sqlite,
+            AlterAction::AddColumn(_)
+            | AlterAction::DropColumn { .. }
+            | AlterAction::RenameColumn { .. }
+            | AlterAction::RenameTo(_),
+        ) => None,
+        (Dialect::Sqlite, _) => Some("the ALTER TABLE action"),
+        _ => None,
+    };
+
+    match unsupported {
+        Some(feature) => Err(DialectError::Unsupported(feature)),
+        None => Ok(()),
+    }
+}
+
+fn alter_action<Sql, Arg>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    action: &AlterAction<'_>,
+) -> Result<(), DialectError>
+where
+    Sql: WriteSql<Arg>,
+{
+    match action {
+        AlterAction::AddColumn(column) => {
+            if dialect == Dialect::MsSql {
+                sql.push_cmd("ADD ");
+            } else {
+                sql.push_cmd("ADD COLUMN ");
+            }
+            column_def(sql, dialect, column)?;
+        }
+        AlterAction::DropColumn { column, if_exists } => {
+            sql.push_cmd("DROP COLUMN ");
+            if *if_exists {
+                sql.push_cmd("IF EXISTS ");
+            }
+            sql.push_cmd(column);
+        }
+        AlterAction::RenameColumn { from, to } => {
+            sql.push_cmd("RENAME COLUMN ");
+            sql.push_cmd(from);
+            sql.push_cmd(" TO ");
+            sql.push_cmd(to);
+        }
+        AlterAction::AlterColumnType {
+            column,
+            data_type: typ,
+            using,
+        } => {
+            match dialect {
+                Dialect::Postgres => {
+                    sql.push_cmd("ALTER COLUMN ");
+                    sql.push_cmd(column);
+                    sql.push_cmd(" TYPE ");
+                }
+                Dialect::MySql => {
+                    sql.push_cmd("MODIFY COLUMN ");
+                    sql.push_cmd(column);
+                    sql.push_cmd(" ");
+                }
+                Dialect::Sqlite | Dialect::MsSql => {
+                    sql.push_cmd("ALTER COLUMN ");
+                    sql.push_cmd(column);
+                    sql.push_cmd(" ");
+                }
+            }
+            data_type(sql, dialect, *typ);
+            if let Some(expr) = using {
+                sql.push_cmd(" USING ");
+                sql.push_cmd(expr);
+            }
+        }
+        AlterAction::SetDefault { column, expr } => {
+            sql.push_cmd("ALTER COLUMN ");
+            sql.push_cmd(column);
+            sql.push_cmd(" SET DEFAULT ");
+            sql.push_cmd(expr);
+        }
+        AlterAction::DropDefault(column) => {
+            sql.push_cmd("ALTER COLUMN ");
+            sql.push_cmd(column);
+            sql.push_cmd(" DROP DEFAULT");
+        }
+        AlterAction::SetNotNull(column) => {
+            sql.push_cmd("ALTER COLUMN ");
+            sql.push_cmd(column);
+            sql.push_cmd(" SET NOT NULL");
+        }
+        AlterAction::DropNotNull(column) => {
+            sql.push_cmd("ALTER COLUMN ");
+            sql.push_cmd(column);
+            sql.push_cmd(" DROP NOT NULL");
+        }
+        AlterAction::AddConstraint {
+            constraint,
+            not_valid,
+        } => {
+            sql.push_cmd("ADD ");
+            table_constraint(sql, constraint);
+            if *not_valid {
+                sql.push_cmd(" NOT VALID");
+            }
+        }
+        AlterAction::DropConstraint(name) => {
+            sql.push_cmd("DROP CONSTRAINT ");
+            sql.push_cmd(name);
+        }
+        AlterAction::ValidateConstraint(name) => {
+            sql.push_cmd("VALIDATE CONSTRAINT ");
+            sql.push_cmd(name);
+        }
+        AlterAction::RenameTo(name) => {
+            sql.push_cmd("RENAME TO ");
+            sql.push_cmd(name);
+        }
+        AlterAction::SetSchema(schema) => {
+            sql.push_cmd("SET SCHEMA ");
+            sql.push_cmd(schema);
+        }
+        AlterAction::AttachPartition { partition, bound } => {
+            sql.push_cmd("ATTACH PARTITION ");
+            sql.push_cmd(partition);
+            sql.push_cmd(" ");
+            sql.push_cmd(bound);
+        }
+        AlterAction::DetachPartition(partition) => {
+            sql.push_cmd("DETACH PARTITION ");
+            sql.push_cmd(partition);
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the `ALTER TABLE` commands of the actions in the dialect.
+///
+/// Actions are combined in a single command when the dialect allows it,
+/// otherwise the commands are separated by `;`. Actions not supported by the
+/// dialect, like most of the actions in SQLite, return [DialectError::Unsupported].
+///
+/// The commands after a `RENAME TO` or `SET SCHEMA` action are written with
+/// the new name of the table.
+///
+/// In MySQL, changing the type of a column is written with `MODIFY COLUMN`,
+/// which resets the attributes of the column absent from the type.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect, DialectError};
+/// # use sqlstr::ddl::{alter_table, AlterTable, ColumnDef, DataType};
+/// # fn main() -> Result<(), DialectError> {
+/// let alter = AlterTable::new("tenant_1.user")
+///     .add_column(ColumnDef::new("bio", DataType::Text))
+///     .alter_column_type("age", DataType::Integer, Some("age::integer"))
+///     .set_not_null("email")
+///     .rename_column("name", "full_name");
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// alter_table(&mut sql, Dialect::Postgres, &alter)?;
+///
+/// assert_eq!(
+///     sql.as_command(),
+///     "ALTER TABLE tenant_1.user \
+///         ADD COLUMN bio TEXT, \
+///         ALTER COLUMN age TYPE INTEGER USING age::integer, \
+///         ALTER COLUMN email SET NOT NULL; \
+///     ALTER TABLE tenant_1.user RENAME COLUMN name TO full_name"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn alter_table<Sql, Arg>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    table: &AlterTable<'_>,
+) -> Result<(), DialectError>
+where
+    Sql: WriteSql<Arg>,
+{
+    for action in &table.actions {
+        check_action(dialect, action)?;
+    }
+
+    // name of the table in the next command
+    let (mut schema, mut name) = match table.name.rsplit_once('.') {
+        Some((schema, name)) => (Some(schema), name),
+        None => (None, table.name),
+    };
+
+    let mut started = false;
+    let mut open = false;
+    for action in &table.actions {
+        let standalone = action.standalone(dialect);
+        if open && !standalone {
+            sql.push_cmd(", ");
+        } else {
+            if started {
+                sql.push_cmd(";");
+            }
+            separator_optional(sql);
+            sql.push_cmd("ALTER TABLE ");
+            if let Some(schema) = schema {
+                sql.push_cmd(schema);
+                sql.push_cmd(".");
+            }
+            sql.push_cmd(name);
+            sql.push_cmd(" ");
+        }
+        alter_action(sql, dialect, action)?;
+        started = true;
+        open = !standalone;
+
+        match action {
+            AlterAction::RenameTo(to) => match to.rsplit_once('.') {
+                Some((to_schema, to_name)) => (schema, name) = (Some(to_schema), to_name),
+                None => name = to,
+            },
+            AlterAction::SetSchema(to) => schema = Some(to),
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ddl::ReferentialAction, test::TestArgs, SqlCommand};
+
+    #[test]
+    fn alter_table_postgres() {
+        let alter = AlterTable::new("account")
+            .drop_column_if_exists("legacy")
+            .set_default("status", "'active'")
+            .drop_default("note")
+            .drop_not_null("note")
+            .add_constraint_not_valid(
+                TableConstraint::foreign_key(["org_id"], "org", ["id"])
+                    .name("account_org_fk")
+                    .on_delete(ReferentialAction::Restrict),
+            )
+            .validate_constraint("account_org_fk")
+            .drop_constraint("account_old_check")
+            .rename_to("customer")
+            .set_schema("archive");
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        alter_table(&mut sql, Dialect::Postgres, &alter).unwrap();
+
+        assert_eq!(
+            sql.as_command(),
+            "ALTER TABLE account \
+                DROP COLUMN IF EXISTS legacy, \
+                ALTER COLUMN status SET DEFAULT 'active', \
+                ALTER COLUMN note DROP DEFAULT, \
+                ALTER COLUMN note DROP NOT NULL, \
+                ADD CONSTRAINT account_org_fk FOREIGN KEY (org_id) REFERENCES org (id) \
+                    ON DELETE RESTRICT NOT VALID, \
+                VALIDATE CONSTRAINT account_org_fk, \
+                DROP CONSTRAINT account_old_check; \
+            ALTER TABLE account RENAME TO customer; \
+            ALTER TABLE customer SET SCHEMA archive"
+        );
+    }
+
+    #[test]
+    fn alter_table_renamed() {
+        let alter = AlterTable::new("tenant_1.account")
+            .rename_to("customer")
+            .set_schema("archive")
+            .add_column(ColumnDef::new("age", DataType::Integer));
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        alter_table(&mut sql, Dialect::Postgres, &alter).unwrap();
+
+        assert_eq!(
+            sql.as_command(),
+            "ALTER TABLE tenant_1.account RENAME TO customer; \
+            ALTER TABLE tenant_1.customer SET SCHEMA archive; \
+            ALTER TABLE archive.customer ADD COLUMN age INTEGER"
+        );
+
+        let alter = AlterTable::new("account")
+            .rename_to("customer")
+            .drop_column("legacy");
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        alter_table(&mut sql, Dialect::Sqlite, &alter).unwrap();
+
+        assert_eq!(
+            sql.as_command(),
+            "ALTER TABLE account RENAME TO customer; \
+            ALTER TABLE customer DROP COLUMN legacy"
+        );
+    }
+
+    #[test]
+    fn alter_table_partition() {
+        let alter = AlterTable::new("event")
+            .attach_partition(
+                "event_2024",
+                "FOR VALUES FROM ('2024-01-01') TO ('2025-01-01')",
+            )
+            .detach_partition("event_2020");
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        alter_table(&mut sql, Dialect::Postgres, &alter).unwrap();
+
+        assert_eq!(
+            sql.as_command(),
+            "ALTER TABLE event ATTACH PARTITION event_2024 \
+                FOR VALUES FROM ('2024-01-01') TO ('2025-01-01'); \
+            ALTER TABLE event DETACH PARTITION event_2020"
+        );
+    }
+
+    #[test]
+    fn alter_table_mysql() {
+        let alter = AlterTable::new("account")
+            .add_column(ColumnDef::new("age", DataType::SmallInt).not_null())
+            .alter_column_type("score", DataType::Double, None)
+            .rename_column("name", "full_name");
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        alter_table(&mut sql, Dialect::MySql, &alter).unwrap();
+
+        assert_eq!(
+            sql.as_command(),
+            "ALTER TABLE account \
+                ADD COLUMN age SMALLINT NOT NULL, \
+                MODIFY COLUMN score DOUBLE, \
+                RENAME COLUMN name TO full_name"
+        );
+
+        let alter = AlterTable::new("account").drop_column_if_exists("legacy");
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        assert_eq!(
+            alter_table(&mut sql, Dialect::MySql, &alter),
+            Err(DialectError::Unsupported("IF EXISTS"))
+        );
+        assert_eq!(sql.as_command(), "");
+    }
+
+    #[test]
+    fn alter_table_sqlite() {
+        let alter = AlterTable::new("account")
+            .add_column(ColumnDef::new("age", DataType::Integer))
+            .drop_column("legacy")
+            .rename_to("customer");
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        alter_table(&mut sql, Dialect::Sqlite, &alter).unwrap();
+
+        assert_eq!(
+            sql.as_command(),
+            "ALTER TABLE account ADD COLUMN age INTEGER; \
+            ALTER TABLE account DROP COLUMN legacy; \
+            ALTER TABLE account RENAME TO customer"
+        );
+
+        let alter = AlterTable::new("account")
+            .add_column(ColumnDef::new("age", DataType::Integer))
+            .set_not_null("age");
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        assert_eq!(
+            alter_table(&mut sql, Dialect::Sqlite, &alter),
+            Err(DialectError::Unsupported("the ALTER TABLE action"))
+        );
+        assert_eq!(sql.as_command(), "");
+    }
+}
@@ -0,0 +1,795 @@
+use alloc::vec::Vec;
+
+use super::{data_type, DataType};
+use crate::{expr::separator_optional, Dialect, DialectError, WriteSql};
+
+/// Identity column generation
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Identity {
+    /// `GENERATED ALWAYS AS IDENTITY`
+    Always,
+    /// `GENERATED BY DEFAULT AS IDENTITY`
+    ByDefault,
+}
+
+impl Identity {
+    pub const fn as_str(&self) -> &'static str {
+        match *self {
+            Self::Always => "GENERATED ALWAYS AS IDENTITY",
+            Self::ByDefault => "GENERATED BY DEFAULT AS IDENTITY",
+        }
+    }
+}
+
+/// Action performed when a referenced row of a foreign key is deleted or updated
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ReferentialAction {
+    NoAction,
+    Restrict,
+    Cascade,
+    SetNull,
+    SetDefault,
+}
+
+impl ReferentialAction {
+    pub const fn as_str(&self) -> &'static str {
+        match *self {
+            Self::NoAction => "NO ACTION",
+            Self::Restrict => "RESTRICT",
+            Self::Cascade => "CASCADE",
+            Self::SetNull => "SET NULL",
+            Self::SetDefault => "SET DEFAULT",
+        }
+    }
+}
+
+/// Column definition of a table
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct ColumnDef<'t> {
+    pub name: &'t str,
+    pub data_type: DataType<'t>,
+    pub not_null: bool,
+    pub default: Option<&'t str>,
+    pub identity: Option<Identity>,
+    pub generated: Option<&'t str>,
+    pub primary_key: bool,
+    pub unique: bool,
+    pub check: Option<&'t str>,
+    pub references: Option<(&'t str, &'t str)>,
+}
+
+impl<'t> ColumnDef<'t> {
+    pub const fn new(name: &'t str, data_type: DataType<'t>) -> Self {
+        Self {
+            name,
+            data_type,
+            not_null: false,
+            default: None,
+            identity: None,
+            generated: None,
+            primary_key: false,
+            unique: false,
+            check: None,
+            references: None,
+        }
+    }
+
+    pub const fn not_null(mut self) -> Self {
+        self.not_null = true;
+        self
+    }
+
+    /// Default value expression, written as is.
+    pub const fn default(mut self, expr: &'t str) -> Self {
+        self.default = Some(expr);
+        self
+    }
+
+    pub const fn identity(mut self, identity: Identity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    /// Generated column, computed from the expression and stored in the table.
+    pub const fn generated_stored(mut self, expr: &'t str) -> Self {
+        self.generated = Some(expr);
+        self
+    }
+
+    pub const fn primary_key(mut self) -> Self {
+        self.primary_key = true;
+        self
+    }
+
+    pub const fn unique(mut self) -> Self {
+        self.unique = true;
+        self
+    }
+
+    pub const fn check(mut self, expr: &'t str) -> Self {
+        self.check = Some(expr);
+        self
+    }
+
+    pub const fn references(mut self, table: &'t str, column: &'t str) -> Self {
+        self.references = Some((table, column));
+        self
+    }
+}
+
+/// Kind of a table constraint
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub enum ConstraintKind<'t> {
+    PrimaryKey(Vec<&'t str>),
+    Unique(Vec<&'t str>),
+    Check(&'t str),
+    ForeignKey {
+        columns: Vec<&'t str>,
+        table: &'t str,
+        ref_columns: Vec<&'t str>,
+        on_delete: Option<ReferentialAction>,
+        on_update: Option<ReferentialAction>,
+    },
+}
+
+/// Table constraint
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct TableConstraint<'t> {
+    pub name: Option<&'t str>,
+    pub kind: ConstraintKind<'t>,
+}
+
+impl<'t> TableConstraint<'t> {
+    pub const fn new(kind: ConstraintKind<'t>) -> Self {
+        Self { name: None, kind }
+    }
+
+    pub fn primary_key<I>(columns: I) -> Self
+    where
+        I: IntoIterator<Item = &'t str>,
+    {
+        Self::new(ConstraintKind::PrimaryKey(columns.into_iter().collect()))
+    }
+
+    pub fn unique<I>(columns: I) -> Self
+    where
+        I: IntoIterator<Item = &'t str>,
+    {
+        Self::new(ConstraintKind::Unique(columns.into_iter().collect()))
+    }
+
+    pub const fn check(expr: &'t str) -> Self {
+        Self::new(ConstraintKind::Check(expr))
+    }
+
+    pub fn foreign_key<I, R>(columns: I, table: &'t str, ref_columns: R) -> Self
+    where
+        I: IntoIterator<Item = &'t str>,
+        R: IntoIterator<Item = &'t str>,
+    {
+        Self::new(ConstraintKind::ForeignKey {
+            columns: columns.into_iter().collect(),
+            table,
+            ref_columns: ref_columns.into_iter().collect(),
+            on_delete: None,
+            on_update: None,
+        })
+    }
+
+    /// Names the constraint: `CONSTRAINT <name>`
+    pub fn name(mut self, name: &'t str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Action of a foreign key when the referenced row is deleted.
+    ///
+    /// Has no effect in the other kinds of constraint.
+    pub fn on_delete(mut self, action: ReferentialAction) -> Self {
+        if let ConstraintKind::ForeignKey { on_delete, .. } = &mut self.kind {
+            *on_delete = Some(action);
+        }
+        self
+    }
+
+    /// Action of a foreign key when the referenced row is updated.
+    ///
+    /// Has no effect in the other kinds of constraint.
+    pub fn on_update(mut self, action: ReferentialAction) -> Self {
+        if let ConstraintKind::ForeignKey { on_update, .. } = &mut self.kind {
+            *on_update = Some(action);
+        }
+        self
+    }
+}
+
+/// Table partitioning strategy
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PartitionStrategy {
+    Range,
+    List,
+    Hash,
+}
+
+impl PartitionStrategy {
+    pub const fn as_str(&self) -> &'static str {
+        match *self {
+            Self::Range => "RANGE",
+            Self::List => "LIST",
+            Self::Hash => "HASH",
+        }
+    }
+}
+
+/// `CREATE TABLE` command builder
+///
+/// The command is written with [create_table].
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct CreateTable<'t> {
+    pub name: &'t str,
+    pub temporary: bool,
+    pub if_not_exists: bool,
+    pub columns: Vec<ColumnDef<'t>>,
+    pub constraints: Vec<TableConstraint<'t>>,
+    pub partition_by: Option<(PartitionStrategy, Vec<&'t str>)>,
+}
+
+impl<'t> CreateTable<'t> {
+    pub const fn new(name: &'t str) -> Self {
+        Self {
+            name,
+            temporary: false,
+            if_not_exists: false,
+            columns: Vec::new(),
+            constraints: Vec::new(),
+            partition_by: None,
+        }
+    }
+
+    pub fn temporary(mut self) -> Self {
+        self.temporary = true;
+        self
+    }
+
+    pub fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    pub fn column(mut self, column: ColumnDef<'t>) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    pub fn constraint(mut self, constraint: TableConstraint<'t>) -> Self {
+        self.constraints.push(constraint);
+        self
+    }
+
+    pub fn partition_by<I>(mut self, strategy: PartitionStrategy, columns: I) -> Self
+    where
+        I: IntoIterator<Item = &'t str>,
+    {
+        self.partition_by = Some((strategy, columns.into_iter().collect()));
+        self
+    }
+}
+
+pub(super) fn name_list<Sql, Arg>(sql: &mut Sql, names: &[&str])
+where
+    Sql: WriteSql<Arg>,
+{
+    sql.push_cmd("(");
+    if let Some(first) = names.first() {
+        sql.push_cmd(first);
+    }
+    for name in names.iter().skip(1) {
+        sql.push_cmd(", ");
+        sql.push_cmd(name);
+    }
+    sql.push_cmd(")");
+}
+
+/// Writes a column definition in the dialect.
+///
+/// MySQL parses the `REFERENCES` of a column definition without creating the
+/// foreign key, a column with [ColumnDef::references] returns
+/// [DialectError::Unsupported] in MySQL. In [create_table] it is written as a
+/// table constraint instead.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect, DialectError};
+/// # use sqlstr::ddl::{column_def, ColumnDef, DataType, Identity};
+/// # fn main() -> Result<(), DialectError> {
+/// let id = ColumnDef::new("id", DataType::BigInt)
+///     .identity(Identity::Always)
+///     .primary_key();
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// column_def(&mut sql, Dialect::Postgres, &id)?;
+/// assert_eq!(sql.as_command(), "id BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY");
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// column_def(&mut sql, Dialect::Sqlite, &id)?;
+/// assert_eq!(sql.as_command(), "id INTEGER PRIMARY KEY AUTOINCREMENT");
+/// # Ok(())
+/// # }
+/// ```
+pub fn column_def<Sql, Arg>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    column: &ColumnDef<'_>,
+) -> Result<(), DialectError>
+where
+    Sql: WriteSql<Arg>,
+{
+    check_column(dialect, column)?;
+    if dialect == Dialect::MySql && column.references.is_some() {
+        return Err(DialectError::Unsupported(
+            "REFERENCES in a column definition",
+        ));
+    }
+    write_column(sql, dialect, column);
+    Ok(())
+}
+
+pub(super) fn check_column(dialect: Dialect, column: &ColumnDef<'_>) -> Result<(), DialectError> {
+    if dialect == Dialect::Sqlite && column.identity.is_some() {
+        if !column.primary_key {
+            return Err(DialectError::Unsupported("identity without PRIMARY KEY"));
+        }
+        // AUTOINCREMENT is only accepted on an INTEGER column
+        if !matches!(
+            column.data_type,
+            DataType::SmallInt | DataType::Integer | DataType::BigInt
+        ) {
+            return Err(DialectError::Unsupported(
+                "identity of a non-integer column",
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Writes a checked column definition, the `REFERENCES` of the column is not
+/// written in MySQL.
+fn write_column<Sql, Arg>(sql: &mut Sql, dialect: Dialect, column: &ColumnDef<'_>)
+where
+    Sql: WriteSql<Arg>,
+{
+    separator_optional(sql);
+    sql.push_cmd(column.name);
+
+    match (dialect, column.generated) {
+        (Dialect::MsSql, Some(expr)) => {
+            sql.push_cmd(" AS (");
+            sql.push_cmd(expr);
+            sql.push_cmd(") PERSISTED");
+        }
+        (_, generated) => {
+            sql.push_cmd(" ");
+            data_type(sql, dialect, column.data_type);
+            if let Some(expr) = generated {
+                sql.push_cmd(" GENERATED ALWAYS AS (");
+                sql.push_cmd(expr);
+                sql.push_cmd(") STORED");
+            }
+        }
+    }
+
+    if let Some(expr) = column.default {
+        sql.push_cmd(" DEFAULT ");
+        sql.push_cmd(expr);
+    }
+
+    if let Some(identity) = column.identity {
+        match dialect {
+            Dialect::Postgres => {
+                sql.push_cmd(" ");
+                sql.push_cmd(identity.as_str());
+            }
+            Dialect::MySql => sql.push_cmd(" AUTO_INCREMENT"),
+            Dialect::MsSql => sql.push_cmd(" IDENTITY(1, 1)"),
+            // written with the primary key
+            Dialect::Sqlite => {}
+        }
+    }
+
+    if column.not_null {
+        sql.push_cmd(" NOT NULL");
+    }
+
+    if column.primary_key {
+        sql.push_cmd(" PRIMARY KEY");
+        if dialect == Dialect::Sqlite && column.identity.is_some() {
+            sql.push_cmd(" AUTOINCREMENT");
+        }
+    }
+
+    if column.unique {
+        sql.push_cmd(" UNIQUE");
+    }
+
+    if let Some(expr) = column.check {
+        sql.push_cmd(" CHECK (");
+        sql.push_cmd(expr);
+        sql.push_cmd(")");
+    }
+
+    match column.references {
+        Some((table, ref_column)) if dialect != Dialect::MySql => {
+            sql.push_cmd(" REFERENCES ");
+            sql.push_cmd(table);
+            sql.push_cmd(" (");
+            sql.push_cmd(ref_column);
+            sql.push_cmd(")");
+        }
+        _ => {}
+    }
+}
+
+/// Writes a table constraint.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void};
+/// # use sqlstr::ddl::{table_constraint, TableConstraint, ReferentialAction};
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// table_constraint(
+///     &mut sql,
+///     &TableConstraint::foreign_key(["org_id"], "org", ["id"])
+///         .name("fk_user_org")
+///         .on_delete(ReferentialAction::Cascade),
+/// );
+///
+/// assert_eq!(
+///     sql.as_command(),
+///     "CONSTRAINT fk_user_org FOREIGN KEY (org_id) REFERENCES org (id) ON DELETE CASCADE"
+/// );
+/// ```
+pub fn table_constraint<Sql, Arg>(sql: &mut Sql, constraint: &TableConstraint<'_>)
+where
+    Sql: WriteSql<Arg>,
+{
+    separator_optional(sql);
+
+    if let Some(name) = constraint.name {
+        sql.push_cmd("CONSTRAINT ");
+        sql.push_cmd(name);
+        sql.push_cmd(" ");
+    }
+
+    match &constraint.kind {
+        ConstraintKind::PrimaryKey(columns) => {
+            sql.push_cmd("PRIMARY KEY ");
+            name_list(sql, columns);
+        }
+        ConstraintKind::Unique(columns) => {
+            sql.push_cmd("UNIQUE ");
+            name_list(sql, columns);
+        }
+        ConstraintKind::Check(expr) => {
+            sql.push_cmd("CHECK (");
+            sql.push_cmd(expr);
+            sql.push_cmd(")");
+        }
+        ConstraintKind::ForeignKey {
+            columns,
+            table,
+            ref_columns,
+            on_delete,
+            on_update,
+        } => {
+            sql.push_cmd("FOREIGN KEY ");
+            name_list(sql, columns);
+            sql.push_cmd(" REFERENCES ");
+            sql.push_cmd(table);
+            if !ref_columns.is_empty() {
+                sql.push_cmd(" ");
+                name_list(sql, ref_columns);
+            }
+            if let Some(action) = on_delete {
+                sql.push_cmd(" ON DELETE ");
+                sql.push_cmd(action.as_str());
+            }
+            if let Some(action) = on_update {
+                sql.push_cmd(" ON UPDATE ");
+                sql.push_cmd(action.as_str());
+            }
+        }
+    }
+}
+
+/// Writes a `CREATE TABLE` command in the dialect.
+///
+/// In MySQL the [ColumnDef::references] of the columns are written as
+/// `FOREIGN KEY` constraints after the other constraints of the table.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect, DialectError};
+/// # use sqlstr::ddl::{
+/// #     create_table, CreateTable, ColumnDef, DataType, Identity, TableConstraint,
+/// #     ReferentialAction,
+/// # };
+/// # fn main() -> Result<(), DialectError> {
+/// let table = CreateTable::new("user")
+///     .if_not_exists()
+///     .column(ColumnDef::new("id", DataType::BigInt).identity(Identity::Always).primary_key())
+///     .column(ColumnDef::new("email", DataType::Varchar(255)).not_null().unique())
+///     .column(ColumnDef::new("org_id", DataType::BigInt).not_null())
+///     .constraint(
+///         TableConstraint::foreign_key(["org_id"], "org", ["id"])
+///             .on_delete(ReferentialAction::Cascade),
+///     );
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// create_table(&mut sql, Dialect::Postgres, &table)?;
+///
+/// assert_eq!(
+///     sql.as_command(),
+///     "CREATE TABLE IF NOT EXISTS user (\
+///         id BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY, \
+///         email VARCHAR(255) NOT NULL UNIQUE, \
+///         org_id BIGINT NOT NULL, \
+///         FOREIGN KEY (org_id) REFERENCES org (id) ON DELETE CASCADE\
+///     )"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn create_table<Sql, Arg>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    table: &CreateTable<'_>,
+) -> Result<(), DialectError>
+where
+    Sql: WriteSql<Arg>,
+{
+    if dialect == Dialect::MsSql {
+        if table.temporary {
+            return Err(DialectError::Unsupported("TEMPORARY"));
+        }
+        if table.if_not_exists {
+            return Err(DialectError::Unsupported("IF NOT EXISTS"));
+        }
+    }
+    if table.partition_by.is_some() {
+        match dialect {
+            Dialect::Sqlite | Dialect::MsSql => {
+                return Err(DialectError::Unsupported("PARTITION BY"))
+            }
+            Dialect::MySql if table.temporary => {
+                return Err(DialectError::Unsupported(
+                    "PARTITION BY of a TEMPORARY table",
+                ))
+            }
+            Dialect::Postgres | Dialect::MySql => {}
+        }
+    }
+    for column in &table.columns {
+        check_column(dialect, column)?;
+    }
+
+    separator_optional(sql);
+    sql.push_cmd("CREATE ");
+    if table.temporary {
+        sql.push_cmd("TEMPORARY ");
+    }
+    sql.push_cmd("TABLE ");
+    if table.if_not_exists {
+        sql.push_cmd("IF NOT EXISTS ");
+    }
+    sql.push_cmd(table.name);
+    sql.push_cmd(" (");
+
+    let mut first = true;
+    for column in &table.columns {
+        if !first {
+            sql.push_cmd(", ");
+        }
+        first = false;
+        write_column(sql, dialect, column);
+    }
+    for constraint in &table.constraints {
+        if !first {
+            sql.push_cmd(", ");
+        }
+        first = false;
+        table_constraint(sql, constraint);
+    }
+    if dialect == Dialect::MySql {
+        // MySQL ignores the REFERENCES of a column definition
+        for column in &table.columns {
+            if let Some((ref_table, ref_column)) = column.references {
+                sql.push_cmd(", FOREIGN KEY (");
+                sql.push_cmd(column.name);
+                sql.push_cmd(") REFERENCES ");
+                sql.push_cmd(ref_table);
+                sql.push_cmd(" (");
+                sql.push_cmd(ref_column);
+                sql.push_cmd(")");
+            }
+        }
+    }
+
+    sql.push_cmd(")");
+
+    if let Some((strategy, columns)) = &table.partition_by {
+        sql.push_cmd(" PARTITION BY ");
+        sql.push_cmd(strategy.as_str());
+        sql.push_cmd(" ");
+        name_list(sql, columns);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::TestArgs, SqlCommand};
+
+    fn event_table() -> CreateTable<'static> {
+        CreateTable::new("event")
+            .temporary()
+            .column(
+                ColumnDef::new("id", DataType::BigInt)
+                    .identity(Identity::ByDefault)
+                    .not_null(),
+            )
+            .column(
+                ColumnDef::new("created", DataType::TimestampTz)
+                    .not_null()
+                    .default("CURRENT_TIMESTAMP"),
+            )
+            .column(ColumnDef::new("price", DataType::Numeric(10, 2)).check("price >= 0"))
+            .column(ColumnDef::new("payload", DataType::Json))
+            .column(ColumnDef::new("total", DataType::Double).generated_stored("price * 2"))
+            .column(ColumnDef::new("user_id", DataType::Uuid).references("user", "id"))
+            .constraint(TableConstraint::primary_key(["id", "created"]).name("event_pkey"))
+            .constraint(TableConstraint::unique(["user_id", "created"]))
+            .constraint(
+                TableConstraint::foreign_key(["user_id"], "user", [])
+                    .on_delete(ReferentialAction::SetNull)
+                    .on_update(ReferentialAction::NoAction),
+            )
+    }
+
+    #[test]
+    fn create_table_postgres() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        create_table(
+            &mut sql,
+            Dialect::Postgres,
+            &event_table().partition_by(PartitionStrategy::Range, ["created"]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            sql.as_command(),
+            "CREATE TEMPORARY TABLE event (\
+                id BIGINT GENERATED BY DEFAULT AS IDENTITY NOT NULL, \
+                created TIMESTAMP WITH TIME ZONE DEFAULT CURRENT_TIMESTAMP NOT NULL, \
+                price NUMERIC(10, 2) CHECK (price >= 0), \
+                payload JSONB, \
+                total DOUBLE PRECISION GENERATED ALWAYS AS (price * 2) STORED, \
+                user_id UUID REFERENCES user (id), \
+                CONSTRAINT event_pkey PRIMARY KEY (id, created), \
+                UNIQUE (user_id, created), \
+                FOREIGN KEY (user_id) REFERENCES user ON DELETE SET NULL ON UPDATE NO ACTION\
+            ) PARTITION BY RANGE (created)"
+        );
+    }
+
+    #[test]
+    fn create_table_mysql() {
+        let table = CreateTable {
+            temporary: false,
+            ..event_table().partition_by(PartitionStrategy::Hash, ["id"])
+        };
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        create_table(&mut sql, Dialect::MySql, &table).unwrap();
+
+        assert_eq!(
+            sql.as_command(),
+            "CREATE TABLE event (\
+                id BIGINT AUTO_INCREMENT NOT NULL, \
+                created TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL, \
+                price DECIMAL(10, 2) CHECK (price >= 0), \
+                payload JSON, \
+                total DOUBLE GENERATED ALWAYS AS (price * 2) STORED, \
+                user_id CHAR(36), \
+                CONSTRAINT event_pkey PRIMARY KEY (id, created), \
+                UNIQUE (user_id, created), \
+                FOREIGN KEY (user_id) REFERENCES user ON DELETE SET NULL ON UPDATE NO ACTION, \
+                FOREIGN KEY (user_id) REFERENCES user (id)\
+            ) PARTITION BY HASH (id)"
+        );
+    }
+
+    #[test]
+    fn create_table_sqlite() {
+        let table = CreateTable::new("tag")
+            .if_not_exists()
+            .column(
+                ColumnDef::new("id", DataType::BigInt)
+                    .identity(Identity::Always)
+                    .primary_key(),
+            )
+            .column(
+                ColumnDef::new("name", DataType::Varchar(64))
+                    .not_null()
+                    .unique(),
+            )
+            .column(ColumnDef::new("active", DataType::Boolean).default("1"));
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        create_table(&mut sql, Dialect::Sqlite, &table).unwrap();
+
+        assert_eq!(
+            sql.as_command(),
+            "CREATE TABLE IF NOT EXISTS tag (\
+                id INTEGER PRIMARY KEY AUTOINCREMENT, \
+                name TEXT NOT NULL UNIQUE, \
+                active INTEGER DEFAULT 1\
+            )"
+        );
+    }
+
+    #[test]
+    fn create_table_unsupported() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        assert_eq!(
+            create_table(&mut sql, Dialect::Sqlite, &event_table()),
+            Err(DialectError::Unsupported("identity without PRIMARY KEY"))
+        );
+
+        let table = CreateTable::new("tag").column(
+            ColumnDef::new("id", DataType::Custom("BIGINT"))
+                .identity(Identity::Always)
+                .primary_key(),
+        );
+        assert_eq!(
+            create_table(&mut sql, Dialect::Sqlite, &table),
+            Err(DialectError::Unsupported(
+                "identity of a non-integer column"
+            ))
+        );
+
+        let table = CreateTable::new("log").partition_by(PartitionStrategy::List, ["kind"]);
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        assert_eq!(
+            create_table(&mut sql, Dialect::Sqlite, &table),
+            Err(DialectError::Unsupported("PARTITION BY"))
+        );
+        assert_eq!(sql.as_command(), "");
+
+        let table = event_table().partition_by(PartitionStrategy::Hash, ["id"]);
+        assert_eq!(
+            create_table(&mut sql, Dialect::MySql, &table),
+            Err(DialectError::Unsupported(
+                "PARTITION BY of a TEMPORARY table"
+            ))
+        );
+        let column = ColumnDef::new("user_id", DataType::BigInt).references("user", "id");
+        assert_eq!(
+            column_def(&mut sql, Dialect::MySql, &column),
+            Err(DialectError::Unsupported(
+                "REFERENCES in a column definition"
+            ))
+        );
+        assert_eq!(sql.as_command(), "");
+    }
+}
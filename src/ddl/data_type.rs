@@ -0,0 +1,156 @@
+use crate::{format_num::format_u32_base10, Dialect, WriteSql};
+
+/// Column data type
+///
+/// The type name is written according to the dialect, using the closest type
+/// available in the database.
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DataType<'t> {
+    SmallInt,
+    Integer,
+    BigInt,
+    Real,
+    Double,
+    /// Exact numeric with precision and scale
+    Numeric(u32, u32),
+    Boolean,
+    Text,
+    Varchar(u32),
+    Char(u32),
+    Bytes,
+    Date,
+    Time,
+    Timestamp,
+    /// Timestamp with time zone
+    TimestampTz,
+    Uuid,
+    Json,
+    /// Type name written as is, for every dialect
+    Custom(&'t str),
+}
+
+fn type_with_len<Sql, Arg>(sql: &mut Sql, name: &str, len: u32)
+where
+    Sql: WriteSql<Arg>,
+{
+    let mut buf = [0; 10];
+    sql.push_cmd(name);
+    sql.push_cmd("(");
+    sql.push_cmd(format_u32_base10(len, &mut buf));
+    sql.push_cmd(")");
+}
+
+fn numeric<Sql, Arg>(sql: &mut Sql, name: &str, precision: u32, scale: u32)
+where
+    Sql: WriteSql<Arg>,
+{
+    let mut buf = [0; 10];
+    sql.push_cmd(name);
+    sql.push_cmd("(");
+    sql.push_cmd(format_u32_base10(precision, &mut buf));
+    sql.push_cmd(", ");
+    sql.push_cmd(format_u32_base10(scale, &mut buf));
+    sql.push_cmd(")");
+}
+
+/// Writes the type name of a column in the dialect.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect};
+/// # use sqlstr::ddl::{data_type, DataType};
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// data_type(&mut sql, Dialect::Postgres, DataType::TimestampTz);
+/// assert_eq!(sql.as_command(), "TIMESTAMP WITH TIME ZONE");
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// data_type(&mut sql, Dialect::MySql, DataType::Uuid);
+/// assert_eq!(sql.as_command(), "CHAR(36)");
+/// ```
+pub fn data_type<Sql, Arg>(sql: &mut Sql, dialect: Dialect, typ: DataType<'_>)
+where
+    Sql: WriteSql<Arg>,
+{
+    match dialect {
+        Dialect::Postgres => match typ {
+            DataType::SmallInt => sql.push_cmd("SMALLINT"),
+            DataType::Integer => sql.push_cmd("INTEGER"),
+            DataType::BigInt => sql.push_cmd("BIGINT"),
+            DataType::Real => sql.push_cmd("REAL"),
+            DataType::Double => sql.push_cmd("DOUBLE PRECISION"),
+            DataType::Numeric(p, s) => numeric(sql, "NUMERIC", p, s),
+            DataType::Boolean => sql.push_cmd("BOOLEAN"),
+            DataType::Text => sql.push_cmd("TEXT"),
+            DataType::Varchar(len) => type_with_len(sql, "VARCHAR", len),
+            DataType::Char(len) => type_with_len(sql, "CHAR", len),
+            DataType::Bytes => sql.push_cmd("BYTEA"),
+            DataType::Date => sql.push_cmd("DATE"),
+            DataType::Time => sql.push_cmd("TIME"),
+            DataType::Timestamp => sql.push_cmd("TIMESTAMP"),
+            DataType::TimestampTz => sql.push_cmd("TIMESTAMP WITH TIME ZONE"),
+            DataType::Uuid => sql.push_cmd("UUID"),
+            DataType::Json => sql.push_cmd("JSONB"),
+            DataType::Custom(name) => sql.push_cmd(name),
+        },
+        Dialect::MySql => match typ {
+            DataType::SmallInt => sql.push_cmd("SMALLINT"),
+            DataType::Integer => sql.push_cmd("INT"),
+            DataType::BigInt => sql.push_cmd("BIGINT"),
+            DataType::Real => sql.push_cmd("FLOAT"),
+            DataType::Double => sql.push_cmd("DOUBLE"),
+            DataType::Numeric(p, s) => numeric(sql, "DECIMAL", p, s),
+            DataType::Boolean => sql.push_cmd("BOOLEAN"),
+            DataType::Text => sql.push_cmd("TEXT"),
+            DataType::Varchar(len) => type_with_len(sql, "VARCHAR", len),
+            DataType::Char(len) => type_with_len(sql, "CHAR", len),
+            DataType::Bytes => sql.push_cmd("LONGBLOB"),
+            DataType::Date => sql.push_cmd("DATE"),
+            DataType::Time => sql.push_cmd("TIME"),
+            DataType::Timestamp => sql.push_cmd("DATETIME"),
+            DataType::TimestampTz => sql.push_cmd("TIMESTAMP"),
+            DataType::Uuid => sql.push_cmd("CHAR(36)"),
+            DataType::Json => sql.push_cmd("JSON"),
+            DataType::Custom(name) => sql.push_cmd(name),
+        },
+        // https://www.sqlite.org/datatype3.html
+        Dialect::Sqlite => match typ {
+            DataType::SmallInt | DataType::Integer | DataType::BigInt | DataType::Boolean => {
+                sql.push_cmd("INTEGER")
+            }
+            DataType::Real | DataType::Double => sql.push_cmd("REAL"),
+            DataType::Numeric(..) => sql.push_cmd("NUMERIC"),
+            DataType::Bytes => sql.push_cmd("BLOB"),
+            DataType::Text
+            | DataType::Varchar(_)
+            | DataType::Char(_)
+            | DataType::Date
+            | DataType::Time
+            | DataType::Timestamp
+            | DataType::TimestampTz
+            | DataType::Uuid
+            | DataType::Json => sql.push_cmd("TEXT"),
+            DataType::Custom(name) => sql.push_cmd(name),
+        },
+        Dialect::MsSql => match typ {
+            DataType::SmallInt => sql.push_cmd("SMALLINT"),
+            DataType::Integer => sql.push_cmd("INT"),
+            DataType::BigInt => sql.push_cmd("BIGINT"),
+            DataType::Real => sql.push_cmd("REAL"),
+            DataType::Double => sql.push_cmd("FLOAT"),
+            DataType::Numeric(p, s) => numeric(sql, "DECIMAL", p, s),
+            DataType::Boolean => sql.push_cmd("BIT"),
+            DataType::Text | DataType::Json => sql.push_cmd("NVARCHAR(MAX)"),
+            DataType::Varchar(len) => type_with_len(sql, "NVARCHAR", len),
+            DataType::Char(len) => type_with_len(sql, "NCHAR", len),
+            DataType::Bytes => sql.push_cmd("VARBINARY(MAX)"),
+            DataType::Date => sql.push_cmd("DATE"),
+            DataType::Time => sql.push_cmd("TIME"),
+            DataType::Timestamp => sql.push_cmd("DATETIME2"),
+            DataType::TimestampTz => sql.push_cmd("DATETIMEOFFSET"),
+            DataType::Uuid => sql.push_cmd("UNIQUEIDENTIFIER"),
+            DataType::Custom(name) => sql.push_cmd(name),
+        },
+    }
+}
@@ -0,0 +1,488 @@
+use alloc::vec::Vec;
+
+use crate::{expr::separator_optional, Dialect, DialectError, WriteSql};
+
+/// Kind of a schema object
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ObjectKind {
+    Table,
+    View,
+    MaterializedView,
+    Sequence,
+    Type,
+    Schema,
+}
+
+impl ObjectKind {
+    pub const fn as_str(&self) -> &'static str {
+        match *self {
+            Self::Table => "TABLE",
+            Self::View => "VIEW",
+            Self::MaterializedView => "MATERIALIZED VIEW",
+            Self::Sequence => "SEQUENCE",
+            Self::Type => "TYPE",
+            Self::Schema => "SCHEMA",
+        }
+    }
+}
+
+/// Behavior with the objects depending on the dropped ones
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DropBehavior {
+    /// Drops the dependent objects too
+    Cascade,
+    /// Refuses to drop when there are dependent objects
+    Restrict,
+}
+
+impl DropBehavior {
+    pub const fn as_str(&self) -> &'static str {
+        match *self {
+            Self::Cascade => "CASCADE",
+            Self::Restrict => "RESTRICT",
+        }
+    }
+}
+
+/// Sequences of identity columns on `TRUNCATE`
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TruncateIdentity {
+    Restart,
+    Continue,
+}
+
+impl TruncateIdentity {
+    pub const fn as_str(&self) -> &'static str {
+        match *self {
+            Self::Restart => "RESTART IDENTITY",
+            Self::Continue => "CONTINUE IDENTITY",
+        }
+    }
+}
+
+fn name_items<'n, Sql, Arg, I>(sql: &mut Sql, names: I)
+where
+    Sql: WriteSql<Arg>,
+    I: IntoIterator<Item = &'n str>,
+{
+    let mut names = names.into_iter();
+    if let Some(name) = names.next() {
+        sql.push_cmd(" ");
+        sql.push_cmd(name);
+    }
+    for name in names {
+        sql.push_cmd(", ");
+        sql.push_cmd(name);
+    }
+}
+
+fn drop_behavior<Sql, Arg>(sql: &mut Sql, behavior: Option<DropBehavior>)
+where
+    Sql: WriteSql<Arg>,
+{
+    if let Some(behavior) = behavior {
+        sql.push_cmd(" ");
+        sql.push_cmd(behavior.as_str());
+    }
+}
+
+fn check_drop(
+    dialect: Dialect,
+    kind: ObjectKind,
+    names: usize,
+    behavior: Option<DropBehavior>,
+) -> Result<(), DialectError> {
+    if dialect == Dialect::Postgres {
+        return Ok(());
+    }
+
+    let unsupported = match (dialect, kind) {
+        (_, ObjectKind::MaterializedView) => Some("DROP MATERIALIZED VIEW"),
+        (_, ObjectKind::Type) => Some("DROP TYPE"),
+        (Dialect::MySql | Dialect::Sqlite, ObjectKind::Sequence) => Some("DROP SEQUENCE"),
+        (Dialect::Sqlite, ObjectKind::Schema) => Some("DROP SCHEMA"),
+        (Dialect::Sqlite, _) if names > 1 => Some("dropping multiple objects"),
+        _ => behavior.map(|behavior| behavior.as_str()),
+    };
+
+    match unsupported {
+        Some(feature) => Err(DialectError::Unsupported(feature)),
+        None => Ok(()),
+    }
+}
+
+/// Writes a `DROP` command of the objects in the dialect.
+///
+/// Materialized views, types and the [DropBehavior] are only written for
+/// PostgreSQL, SQLite drops one object per command and has no sequences or
+/// schemas, MySQL has no sequences.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect, DialectError};
+/// # use sqlstr::ddl::{drop_objects, ObjectKind, DropBehavior};
+/// # fn main() -> Result<(), DialectError> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// drop_objects(&mut sql, Dialect::Sqlite, ObjectKind::View, true, ["active_user"], None)?;
+///
+/// assert_eq!(sql.as_command(), "DROP VIEW IF EXISTS active_user");
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// drop_objects(
+///     &mut sql,
+///     Dialect::Postgres,
+///     ObjectKind::Schema,
+///     false,
+///     ["tenant_1"],
+///     Some(DropBehavior::Cascade),
+/// )?;
+///
+/// assert_eq!(sql.as_command(), "DROP SCHEMA tenant_1 CASCADE");
+/// # Ok(())
+/// # }
+/// ```
+pub fn drop_objects<'n, Sql, Arg, I>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    kind: ObjectKind,
+    if_exists: bool,
+    names: I,
+    behavior: Option<DropBehavior>,
+) -> Result<(), DialectError>
+where
+    Sql: WriteSql<Arg>,
+    I: IntoIterator<Item = &'n str>,
+{
+    let names: Vec<&str> = names.into_iter().collect();
+    check_drop(dialect, kind, names.len(), behavior)?;
+
+    separator_optional(sql);
+    sql.push_cmd("DROP ");
+    sql.push_cmd(kind.as_str());
+    if if_exists {
+        sql.push_cmd(" IF EXISTS");
+    }
+    name_items(sql, names);
+    drop_behavior(sql, behavior);
+    Ok(())
+}
+
+/// Writes a `DROP TABLE` command in the dialect, see [drop_objects].
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect, DialectError};
+/// # use sqlstr::ddl::{drop_tables, DropBehavior};
+/// # fn main() -> Result<(), DialectError> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// drop_tables(&mut sql, Dialect::Postgres, true, ["user", "org"], Some(DropBehavior::Restrict))?;
+///
+/// assert_eq!(sql.as_command(), "DROP TABLE IF EXISTS user, org RESTRICT");
+/// # Ok(())
+/// # }
+/// ```
+pub fn drop_tables<'t, Sql, Arg, I>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    if_exists: bool,
+    tables: I,
+    behavior: Option<DropBehavior>,
+) -> Result<(), DialectError>
+where
+    Sql: WriteSql<Arg>,
+    I: IntoIterator<Item = &'t str>,
+{
+    drop_objects(sql, dialect, ObjectKind::Table, if_exists, tables, behavior)
+}
+
+/// Writes a `TRUNCATE` command in the dialect.
+///
+/// MySQL and MSSQL truncate one table per `TRUNCATE TABLE` command, without
+/// the identity and the [DropBehavior] options, SQLite has no `TRUNCATE`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect, DialectError};
+/// # use sqlstr::ddl::{truncate, DropBehavior, TruncateIdentity};
+/// # fn main() -> Result<(), DialectError> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// truncate(
+///     &mut sql,
+///     Dialect::Postgres,
+///     ["user", "org"],
+///     Some(TruncateIdentity::Restart),
+///     Some(DropBehavior::Cascade),
+/// )?;
+///
+/// assert_eq!(sql.as_command(), "TRUNCATE user, org RESTART IDENTITY CASCADE");
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// truncate(&mut sql, Dialect::MySql, ["user"], None, None)?;
+///
+/// assert_eq!(sql.as_command(), "TRUNCATE TABLE user");
+/// # Ok(())
+/// # }
+/// ```
+pub fn truncate<'t, Sql, Arg, I>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    tables: I,
+    identity: Option<TruncateIdentity>,
+    behavior: Option<DropBehavior>,
+) -> Result<(), DialectError>
+where
+    Sql: WriteSql<Arg>,
+    I: IntoIterator<Item = &'t str>,
+{
+    let tables: Vec<&str> = tables.into_iter().collect();
+    match dialect {
+        Dialect::Postgres => {}
+        Dialect::Sqlite => return Err(DialectError::Unsupported("TRUNCATE")),
+        Dialect::MySql | Dialect::MsSql => {
+            if tables.len() > 1 {
+                return Err(DialectError::Unsupported("truncating multiple tables"));
+            }
+            if let Some(identity) = identity {
+                return Err(DialectError::Unsupported(identity.as_str()));
+            }
+            if let Some(behavior) = behavior {
+                return Err(DialectError::Unsupported(behavior.as_str()));
+            }
+        }
+    }
+
+    separator_optional(sql);
+    sql.push_cmd("TRUNCATE");
+    if dialect != Dialect::Postgres {
+        sql.push_cmd(" TABLE");
+    }
+    name_items(sql, tables);
+    if let Some(identity) = identity {
+        sql.push_cmd(" ");
+        sql.push_cmd(identity.as_str());
+    }
+    drop_behavior(sql, behavior);
+    Ok(())
+}
+
+/// Writes a `ALTER ... RENAME TO` command in the dialect.
+///
+/// Outside of PostgreSQL only tables are renamed, by MySQL and SQLite, MSSQL
+/// renames objects with `sp_rename`.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect, DialectError};
+/// # use sqlstr::ddl::{alter_rename, ObjectKind};
+/// # fn main() -> Result<(), DialectError> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// alter_rename(
+///     &mut sql,
+///     Dialect::Postgres,
+///     ObjectKind::Sequence,
+///     "user_id_seq",
+///     "account_id_seq",
+/// )?;
+///
+/// assert_eq!(sql.as_command(), "ALTER SEQUENCE user_id_seq RENAME TO account_id_seq");
+/// # Ok(())
+/// # }
+/// ```
+pub fn alter_rename<Sql, Arg>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    kind: ObjectKind,
+    name: &str,
+    new_name: &str,
+) -> Result<(), DialectError>
+where
+    Sql: WriteSql<Arg>,
+{
+    match (dialect, kind) {
+        (Dialect::Postgres, _) | (Dialect::MySql | Dialect::Sqlite, ObjectKind::Table) => {}
+        _ => return Err(DialectError::Unsupported("ALTER ... RENAME TO")),
+    }
+
+    separator_optional(sql);
+    sql.push_cmd("ALTER ");
+    sql.push_cmd(kind.as_str());
+    sql.push_cmd(" ");
+    sql.push_cmd(name);
+    sql.push_cmd(" RENAME TO ");
+    sql.push_cmd(new_name);
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::TestArgs, SqlCommand};
+
+    #[test]
+    fn drop_multiple_objects() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        drop_objects(
+            &mut sql,
+            Dialect::Postgres,
+            ObjectKind::Type,
+            false,
+            ["mood", "color"],
+            Some(DropBehavior::Restrict),
+        )
+        .unwrap();
+        assert_eq!(sql.as_command(), "DROP TYPE mood, color RESTRICT");
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        drop_objects(
+            &mut sql,
+            Dialect::Postgres,
+            ObjectKind::MaterializedView,
+            true,
+            ["stats"],
+            None,
+        )
+        .unwrap();
+        assert_eq!(sql.as_command(), "DROP MATERIALIZED VIEW IF EXISTS stats");
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        drop_tables(&mut sql, Dialect::MsSql, true, ["user", "org"], None).unwrap();
+        assert_eq!(sql.as_command(), "DROP TABLE IF EXISTS user, org");
+    }
+
+    #[test]
+    fn drop_unsupported() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        for (dialect, kind, names, behavior, feature) in [
+            (
+                Dialect::MySql,
+                ObjectKind::MaterializedView,
+                ["stats"].as_slice(),
+                None,
+                "DROP MATERIALIZED VIEW",
+            ),
+            (
+                Dialect::MsSql,
+                ObjectKind::Type,
+                &["mood"],
+                None,
+                "DROP TYPE",
+            ),
+            (
+                Dialect::Sqlite,
+                ObjectKind::Sequence,
+                &["id_seq"],
+                None,
+                "DROP SEQUENCE",
+            ),
+            (
+                Dialect::Sqlite,
+                ObjectKind::Table,
+                &["user", "org"],
+                None,
+                "dropping multiple objects",
+            ),
+            (
+                Dialect::MySql,
+                ObjectKind::Table,
+                &["user"],
+                Some(DropBehavior::Cascade),
+                "CASCADE",
+            ),
+        ] {
+            assert_eq!(
+                drop_objects(
+                    &mut sql,
+                    dialect,
+                    kind,
+                    false,
+                    names.iter().copied(),
+                    behavior
+                ),
+                Err(DialectError::Unsupported(feature))
+            );
+        }
+        assert_eq!(sql.as_command(), "");
+    }
+
+    #[test]
+    fn truncate_then_drop() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        truncate(
+            &mut sql,
+            Dialect::Postgres,
+            ["event"],
+            Some(TruncateIdentity::Continue),
+            None,
+        )
+        .unwrap();
+        sql.push_cmd(";");
+        drop_tables(&mut sql, Dialect::Postgres, false, ["event"], None).unwrap();
+
+        assert_eq!(
+            sql.as_command(),
+            "TRUNCATE event CONTINUE IDENTITY; DROP TABLE event"
+        );
+    }
+
+    #[test]
+    fn truncate_dialects() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        truncate(&mut sql, Dialect::MsSql, ["event"], None, None).unwrap();
+        assert_eq!(sql.as_command(), "TRUNCATE TABLE event");
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        assert_eq!(
+            truncate(&mut sql, Dialect::Sqlite, ["event"], None, None),
+            Err(DialectError::Unsupported("TRUNCATE"))
+        );
+        assert_eq!(
+            truncate(&mut sql, Dialect::MySql, ["event", "log"], None, None),
+            Err(DialectError::Unsupported("truncating multiple tables"))
+        );
+        assert_eq!(
+            truncate(
+                &mut sql,
+                Dialect::MsSql,
+                ["event"],
+                Some(TruncateIdentity::Restart),
+                None
+            ),
+            Err(DialectError::Unsupported("RESTART IDENTITY"))
+        );
+        assert_eq!(sql.as_command(), "");
+    }
+
+    #[test]
+    fn alter_rename_dialects() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        alter_rename(
+            &mut sql,
+            Dialect::Sqlite,
+            ObjectKind::Table,
+            "user",
+            "account",
+        )
+        .unwrap();
+        assert_eq!(sql.as_command(), "ALTER TABLE user RENAME TO account");
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        for (dialect, kind) in [
+            (Dialect::MySql, ObjectKind::Sequence),
+            (Dialect::Sqlite, ObjectKind::View),
+            (Dialect::MsSql, ObjectKind::Table),
+        ] {
+            assert_eq!(
+                alter_rename(&mut sql, dialect, kind, "a", "b"),
+                Err(DialectError::Unsupported("ALTER ... RENAME TO"))
+            );
+        }
+        assert_eq!(sql.as_command(), "");
+    }
+}
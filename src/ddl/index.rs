@@ -0,0 +1,639 @@
+use alloc::vec::Vec;
+
+use super::name_list;
+use crate::{
+    expr::{order_by_expr, separator_optional, OrderByNulls, OrderByOrd},
+    Dialect, DialectError, WriteSql,
+};
+
+/// Index access method
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum IndexMethod {
+    BTree,
+    Hash,
+    Gin,
+    Gist,
+    Brin,
+}
+
+impl IndexMethod {
+    pub const fn as_str(&self) -> &'static str {
+        match *self {
+            Self::BTree => "btree",
+            Self::Hash => "hash",
+            Self::Gin => "gin",
+            Self::Gist => "gist",
+            Self::Brin => "brin",
+        }
+    }
+}
+
+/// Indexed column or expression
+///
+/// Expressions other than a column name must be enclosed in parentheses, like
+/// `(lower(email))`.
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct IndexColumn<'i> {
+    pub expr: &'i str,
+    pub opclass: Option<&'i str>,
+    pub order: OrderByOrd<'i>,
+    pub nulls: OrderByNulls,
+}
+
+impl<'i> IndexColumn<'i> {
+    pub const fn new(expr: &'i str) -> Self {
+        Self {
+            expr,
+            opclass: None,
+            order: OrderByOrd::Default,
+            nulls: OrderByNulls::Default,
+        }
+    }
+
+    /// Operator class of the column, like `text_pattern_ops`.
+    pub const fn opclass(mut self, opclass: &'i str) -> Self {
+        self.opclass = Some(opclass);
+        self
+    }
+
+    pub fn order(mut self, order: OrderByOrd<'i>) -> Self {
+        self.order = order;
+        self
+    }
+
+    pub fn nulls(mut self, nulls: OrderByNulls) -> Self {
+        self.nulls = nulls;
+        self
+    }
+}
+
+impl<'i> From<&'i str> for IndexColumn<'i> {
+    fn from(expr: &'i str) -> Self {
+        Self::new(expr)
+    }
+}
+
+/// `CREATE INDEX` command builder
+///
+/// The command is written with [create_index].
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct CreateIndex<'i> {
+    pub name: &'i str,
+    pub table: &'i str,
+    pub unique: bool,
+    pub concurrently: bool,
+    pub if_not_exists: bool,
+    pub method: Option<IndexMethod>,
+    pub columns: Vec<IndexColumn<'i>>,
+    pub include: Vec<&'i str>,
+    pub storage: Vec<(&'i str, &'i str)>,
+    pub predicate: Option<&'i str>,
+}
+
+impl<'i> CreateIndex<'i> {
+    pub const fn new(name: &'i str, table: &'i str) -> Self {
+        Self {
+            name,
+            table,
+            unique: false,
+            concurrently: false,
+            if_not_exists: false,
+            method: None,
+            columns: Vec::new(),
+            include: Vec::new(),
+            storage: Vec::new(),
+            predicate: None,
+        }
+    }
+
+    pub fn unique(mut self) -> Self {
+        self.unique = true;
+        self
+    }
+
+    pub fn concurrently(mut self) -> Self {
+        self.concurrently = true;
+        self
+    }
+
+    pub fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    pub fn method(mut self, method: IndexMethod) -> Self {
+        self.method = Some(method);
+        self
+    }
+
+    pub fn column<C>(mut self, column: C) -> Self
+    where
+        C: Into<IndexColumn<'i>>,
+    {
+        self.columns.push(column.into());
+        self
+    }
+
+    /// Non-key columns stored in the index: `INCLUDE (...)`
+    pub fn include<I>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = &'i str>,
+    {
+        self.include.extend(columns);
+        self
+    }
+
+    /// Storage parameter written in the `WITH (...)` clause, the value is written as is.
+    pub fn storage(mut self, param: &'i str, value: &'i str) -> Self {
+        self.storage.push((param, value));
+        self
+    }
+
+    /// Partial index predicate: `WHERE <predicate>`
+    pub fn predicate(mut self, predicate: &'i str) -> Self {
+        self.predicate = Some(predicate);
+        self
+    }
+}
+
+fn check_create_index(dialect: Dialect, index: &CreateIndex<'_>) -> Result<(), DialectError> {
+    if index
+        .columns
+        .iter()
+        .any(|col| matches!(col.order, OrderByOrd::Using(_)))
+    {
+        return Err(DialectError::Unsupported("USING operator in index columns"));
+    }
+
+    let unsupported = match dialect {
+        Dialect::Postgres => None,
+        _ if index.concurrently => Some("CONCURRENTLY"),
+        _ if index.columns.iter().any(|col| col.opclass.is_some()) => Some("operator class"),
+        _ if index
+            .columns
+            .iter()
+            .any(|col| col.nulls != OrderByNulls::Default) =>
+        {
+            Some("NULLS FIRST/LAST in index columns")
+        }
+        Dialect::MySql | Dialect::MsSql if index.if_not_exists => Some("IF NOT EXISTS"),
+        Dialect::MySql | Dialect::Sqlite if !index.include.is_empty() => Some("INCLUDE"),
+        Dialect::MySql | Dialect::Sqlite if !index.storage.is_empty() => Some("WITH"),
+        Dialect::MySql if index.predicate.is_some() => Some("partial index"),
+        Dialect::MySql => match index.method {
+            Some(IndexMethod::BTree | IndexMethod::Hash) | None => None,
+            Some(_) => Some("index method"),
+        },
+        Dialect::Sqlite | Dialect::MsSql if index.method.is_some() => Some("index method"),
+        Dialect::Sqlite | Dialect::MsSql => None,
+    };
+
+    match unsupported {
+        Some(feature) => Err(DialectError::Unsupported(feature)),
+        None => Ok(()),
+    }
+}
+
+/// Writes a `CREATE INDEX` command in the dialect.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect, DialectError};
+/// # use sqlstr::expr::{OrderByOrd, OrderByNulls};
+/// # use sqlstr::ddl::{create_index, CreateIndex, IndexColumn, IndexMethod};
+/// # fn main() -> Result<(), DialectError> {
+/// let index = CreateIndex::new("user_email_idx", "user")
+///     .unique()
+///     .concurrently()
+///     .method(IndexMethod::BTree)
+///     .column("(lower(email))")
+///     .column(IndexColumn::new("created").order(OrderByOrd::Desc).nulls(OrderByNulls::Last))
+///     .include(["name"])
+///     .predicate("deleted IS NULL");
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// create_index(&mut sql, Dialect::Postgres, &index)?;
+///
+/// assert_eq!(
+///     sql.as_command(),
+///     "CREATE UNIQUE INDEX CONCURRENTLY user_email_idx ON user USING btree \
+///     ((lower(email)), created DESC NULLS LAST) INCLUDE (name) WHERE deleted IS NULL"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn create_index<Sql, Arg>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    index: &CreateIndex<'_>,
+) -> Result<(), DialectError>
+where
+    Sql: WriteSql<Arg>,
+{
+    check_create_index(dialect, index)?;
+
+    separator_optional(sql);
+    sql.push_cmd("CREATE ");
+    if index.unique {
+        sql.push_cmd("UNIQUE ");
+    }
+    sql.push_cmd("INDEX ");
+    if index.concurrently {
+        sql.push_cmd("CONCURRENTLY ");
+    }
+    if index.if_not_exists {
+        sql.push_cmd("IF NOT EXISTS ");
+    }
+    sql.push_cmd(index.name);
+
+    if let (Dialect::MySql, Some(method)) = (dialect, index.method) {
+        sql.push_cmd(" USING ");
+        match method {
+            IndexMethod::Hash => sql.push_cmd("HASH"),
+            _ => sql.push_cmd("BTREE"),
+        }
+    }
+
+    sql.push_cmd(" ON ");
+    sql.push_cmd(index.table);
+
+    if let (Dialect::Postgres, Some(method)) = (dialect, index.method) {
+        sql.push_cmd(" USING ");
+        sql.push_cmd(method.as_str());
+    }
+
+    sql.push_cmd(" (");
+    for (i, column) in index.columns.iter().enumerate() {
+        if i > 0 {
+            sql.push_cmd(", ");
+        }
+        match column.opclass {
+            Some(opclass) => {
+                sql.push_cmd(column.expr);
+                sql.push_cmd(" ");
+                order_by_expr(sql, opclass, column.order.clone(), column.nulls.clone());
+            }
+            None => order_by_expr(sql, column.expr, column.order.clone(), column.nulls.clone()),
+        }
+    }
+    sql.push_cmd(")");
+
+    if !index.include.is_empty() {
+        sql.push_cmd(" INCLUDE ");
+        name_list(sql, &index.include);
+    }
+
+    let storage = |sql: &mut Sql| {
+        if !index.storage.is_empty() {
+            sql.push_cmd(" WITH (");
+            for (i, (param, value)) in index.storage.iter().enumerate() {
+                if i > 0 {
+                    sql.push_cmd(", ");
+                }
+                sql.push_cmd(param);
+                sql.push_cmd(" = ");
+                sql.push_cmd(value);
+            }
+            sql.push_cmd(")");
+        }
+    };
+
+    // MSSQL writes the index options after the filter
+    if dialect != Dialect::MsSql {
+        storage(sql);
+    }
+    if let Some(predicate) = index.predicate {
+        sql.push_cmd(" WHERE ");
+        sql.push_cmd(predicate);
+    }
+    if dialect == Dialect::MsSql {
+        storage(sql);
+    }
+
+    Ok(())
+}
+
+/// `DROP INDEX` command builder
+///
+/// The command is written with [drop_index].
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct DropIndex<'i> {
+    pub names: Vec<&'i str>,
+    pub table: Option<&'i str>,
+    pub concurrently: bool,
+    pub if_exists: bool,
+}
+
+impl<'i> DropIndex<'i> {
+    pub fn new<I>(names: I) -> Self
+    where
+        I: IntoIterator<Item = &'i str>,
+    {
+        Self {
+            names: names.into_iter().collect(),
+            table: None,
+            concurrently: false,
+            if_exists: false,
+        }
+    }
+
+    /// Table of the index, required by MySQL and MSSQL.
+    pub fn on(mut self, table: &'i str) -> Self {
+        self.table = Some(table);
+        self
+    }
+
+    pub fn concurrently(mut self) -> Self {
+        self.concurrently = true;
+        self
+    }
+
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+}
+
+/// Writes a `DROP INDEX` command in the dialect.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect, DialectError};
+/// # use sqlstr::ddl::{drop_index, DropIndex};
+/// # fn main() -> Result<(), DialectError> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// drop_index(&mut sql, Dialect::Postgres, &DropIndex::new(["a_idx", "b_idx"]).if_exists())?;
+/// assert_eq!(sql.as_command(), "DROP INDEX IF EXISTS a_idx, b_idx");
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// drop_index(&mut sql, Dialect::MySql, &DropIndex::new(["a_idx"]).on("user"))?;
+/// assert_eq!(sql.as_command(), "DROP INDEX a_idx ON user");
+/// # Ok(())
+/// # }
+/// ```
+pub fn drop_index<Sql, Arg>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    index: &DropIndex<'_>,
+) -> Result<(), DialectError>
+where
+    Sql: WriteSql<Arg>,
+{
+    if dialect != Dialect::Postgres {
+        if index.concurrently {
+            return Err(DialectError::Unsupported("CONCURRENTLY"));
+        }
+        if index.names.len() > 1 {
+            return Err(DialectError::Unsupported("dropping multiple indexes"));
+        }
+    }
+    if index.concurrently && index.names.len() > 1 {
+        return Err(DialectError::Unsupported(
+            "CONCURRENTLY with multiple indexes",
+        ));
+    }
+    if dialect == Dialect::MySql && index.if_exists {
+        return Err(DialectError::Unsupported("IF EXISTS"));
+    }
+    if matches!(dialect, Dialect::MySql | Dialect::MsSql) && index.table.is_none() {
+        return Err(DialectError::Unsupported("DROP INDEX without the table"));
+    }
+
+    separator_optional(sql);
+    sql.push_cmd("DROP INDEX ");
+    if index.concurrently {
+        sql.push_cmd("CONCURRENTLY ");
+    }
+    if index.if_exists {
+        sql.push_cmd("IF EXISTS ");
+    }
+    for (i, name) in index.names.iter().enumerate() {
+        if i > 0 {
+            sql.push_cmd(", ");
+        }
+        sql.push_cmd(name);
+    }
+
+    if let (Dialect::MySql | Dialect::MsSql, Some(table)) = (dialect, index.table) {
+        sql.push_cmd(" ON ");
+        sql.push_cmd(table);
+    }
+
+    Ok(())
+}
+
+/// Object rebuilt by `REINDEX`
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ReindexTarget<'i> {
+    Index(&'i str),
+    Table(&'i str),
+    Schema(&'i str),
+    Database(&'i str),
+}
+
+/// Writes a `REINDEX` command in the dialect.
+///
+/// Only PostgreSQL and SQLite have the command, SQLite rebuilds indexes and
+/// tables by name.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect, DialectError};
+/// # use sqlstr::ddl::{reindex, ReindexTarget};
+/// # fn main() -> Result<(), DialectError> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// reindex(&mut sql, Dialect::Postgres, ReindexTarget::Table("user"), true)?;
+/// assert_eq!(sql.as_command(), "REINDEX TABLE CONCURRENTLY user");
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// reindex(&mut sql, Dialect::Sqlite, ReindexTarget::Index("user_email_idx"), false)?;
+/// assert_eq!(sql.as_command(), "REINDEX user_email_idx");
+/// # Ok(())
+/// # }
+/// ```
+pub fn reindex<Sql, Arg>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    target: ReindexTarget<'_>,
+    concurrently: bool,
+) -> Result<(), DialectError>
+where
+    Sql: WriteSql<Arg>,
+{
+    match dialect {
+        Dialect::Postgres => {
+            let (kind, name) = match target {
+                ReindexTarget::Index(name) => ("INDEX ", name),
+                ReindexTarget::Table(name) => ("TABLE ", name),
+                ReindexTarget::Schema(name) => ("SCHEMA ", name),
+                ReindexTarget::Database(name) => ("DATABASE ", name),
+            };
+
+            separator_optional(sql);
+            sql.push_cmd("REINDEX ");
+            sql.push_cmd(kind);
+            if concurrently {
+                sql.push_cmd("CONCURRENTLY ");
+            }
+            sql.push_cmd(name);
+        }
+        Dialect::Sqlite => {
+            if concurrently {
+                return Err(DialectError::Unsupported("CONCURRENTLY"));
+            }
+            let name = match target {
+                ReindexTarget::Index(name) | ReindexTarget::Table(name) => name,
+                ReindexTarget::Schema(_) | ReindexTarget::Database(_) => {
+                    return Err(DialectError::Unsupported("REINDEX of a schema or database"))
+                }
+            };
+
+            separator_optional(sql);
+            sql.push_cmd("REINDEX ");
+            sql.push_cmd(name);
+        }
+        Dialect::MySql | Dialect::MsSql => return Err(DialectError::Unsupported("REINDEX")),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::TestArgs, SqlCommand};
+
+    #[test]
+    fn create_index_postgres() {
+        let index = CreateIndex::new("doc_tags_idx", "doc")
+            .if_not_exists()
+            .method(IndexMethod::Gin)
+            .column(IndexColumn::new("tags").opclass("jsonb_path_ops"))
+            .column(
+                IndexColumn::new("title")
+                    .opclass("text_pattern_ops")
+                    .order(OrderByOrd::Asc),
+            )
+            .storage("fastupdate", "off")
+            .storage("gin_pending_list_limit", "128")
+            .predicate("archived = false");
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        create_index(&mut sql, Dialect::Postgres, &index).unwrap();
+
+        assert_eq!(
+            sql.as_command(),
+            "CREATE INDEX IF NOT EXISTS doc_tags_idx ON doc USING gin \
+            (tags jsonb_path_ops, title text_pattern_ops ASC) \
+            WITH (fastupdate = off, gin_pending_list_limit = 128) \
+            WHERE archived = false"
+        );
+    }
+
+    #[test]
+    fn create_index_dialects() {
+        let index = CreateIndex::new("user_name_idx", "user")
+            .unique()
+            .method(IndexMethod::Hash)
+            .column("name")
+            .column(IndexColumn::new("age").order(OrderByOrd::Desc));
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        create_index(&mut sql, Dialect::MySql, &index).unwrap();
+        assert_eq!(
+            sql.as_command(),
+            "CREATE UNIQUE INDEX user_name_idx USING HASH ON user (name, age DESC)"
+        );
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        assert_eq!(
+            create_index(&mut sql, Dialect::Sqlite, &index),
+            Err(DialectError::Unsupported("index method"))
+        );
+
+        let index = CreateIndex::new("user_name_idx", "user")
+            .column("name")
+            .include(["email"])
+            .storage("ONLINE", "ON")
+            .predicate("active = 1");
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        create_index(&mut sql, Dialect::MsSql, &index).unwrap();
+        assert_eq!(
+            sql.as_command(),
+            "CREATE INDEX user_name_idx ON user (name) INCLUDE (email) \
+            WHERE active = 1 WITH (ONLINE = ON)"
+        );
+    }
+
+    #[test]
+    fn drop_index_dialects() {
+        let index = DropIndex::new(["user_name_idx"]).concurrently();
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        drop_index(&mut sql, Dialect::Postgres, &index).unwrap();
+        assert_eq!(sql.as_command(), "DROP INDEX CONCURRENTLY user_name_idx");
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        assert_eq!(
+            drop_index(
+                &mut sql,
+                Dialect::Postgres,
+                &DropIndex::new(["a_idx", "b_idx"]).concurrently()
+            ),
+            Err(DialectError::Unsupported(
+                "CONCURRENTLY with multiple indexes"
+            ))
+        );
+        assert_eq!(sql.as_command(), "");
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        assert_eq!(
+            drop_index(&mut sql, Dialect::Sqlite, &index),
+            Err(DialectError::Unsupported("CONCURRENTLY"))
+        );
+
+        let index = DropIndex::new(["user_name_idx"]).if_exists().on("user");
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        drop_index(&mut sql, Dialect::MsSql, &index).unwrap();
+        assert_eq!(
+            sql.as_command(),
+            "DROP INDEX IF EXISTS user_name_idx ON user"
+        );
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        drop_index(&mut sql, Dialect::Sqlite, &index).unwrap();
+        assert_eq!(sql.as_command(), "DROP INDEX IF EXISTS user_name_idx");
+    }
+
+    #[test]
+    fn reindex_unsupported() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        assert_eq!(
+            reindex(
+                &mut sql,
+                Dialect::MySql,
+                ReindexTarget::Table("user"),
+                false
+            ),
+            Err(DialectError::Unsupported("REINDEX"))
+        );
+
+        reindex(
+            &mut sql,
+            Dialect::Postgres,
+            ReindexTarget::Schema("app"),
+            false,
+        )
+        .unwrap();
+        assert_eq!(sql.as_command(), "REINDEX SCHEMA app");
+    }
+}
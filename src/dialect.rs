@@ -0,0 +1,36 @@
+/// SQL dialect of the database the command is written for.
+///
+/// Writers that render different syntax for each database take the dialect
+/// as an argument; every other writer produces the same SQL for all of them.
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub enum Dialect {
+    #[default]
+    Postgres,
+    MySql,
+    Sqlite,
+    MsSql,
+}
+
+/// Error of a command that can not be written in the dialect.
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DialectError {
+    /// The feature is not supported by the dialect.
+    Unsupported(&'static str),
+}
+
+#[cfg(feature = "fmt")]
+impl core::fmt::Display for DialectError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Unsupported(feature) => {
+                f.write_str(feature)?;
+                f.write_str(" is not supported by the dialect")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DialectError {}
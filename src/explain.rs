@@ -0,0 +1,268 @@
+//! `EXPLAIN` of built commands.
+//!
+//! With the `std` feature, the JSON plans of PostgreSQL can be deserialized
+//! with `parse_plan`.
+
+#[cfg(feature = "std")]
+mod plan;
+
+#[cfg(feature = "std")]
+pub use plan::*;
+
+use alloc::string::String;
+
+use crate::{Dialect, DialectError, SqlCommand};
+
+/// Output format of the plan
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ExplainFormat {
+    /// `TEXT` in PostgreSQL, `TRADITIONAL` in MySQL
+    Text,
+    Json,
+    Xml,
+    Yaml,
+    /// Only available in MySQL
+    Tree,
+}
+
+/// Options of the `EXPLAIN` command
+///
+/// SQLite has no options, every option set returns an error for it.
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExplainOptions {
+    pub analyze: bool,
+    pub buffers: bool,
+    pub verbose: bool,
+    pub settings: bool,
+    pub format: Option<ExplainFormat>,
+}
+
+impl ExplainOptions {
+    pub const fn new() -> Self {
+        Self {
+            analyze: false,
+            buffers: false,
+            verbose: false,
+            settings: false,
+            format: None,
+        }
+    }
+
+    /// Executes the command to show the actual run times.
+    pub const fn analyze(mut self) -> Self {
+        self.analyze = true;
+        self
+    }
+
+    pub const fn buffers(mut self) -> Self {
+        self.buffers = true;
+        self
+    }
+
+    pub const fn verbose(mut self) -> Self {
+        self.verbose = true;
+        self
+    }
+
+    pub const fn settings(mut self) -> Self {
+        self.settings = true;
+        self
+    }
+
+    pub const fn format(mut self, format: ExplainFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    const fn is_empty(&self) -> bool {
+        !self.analyze && !self.buffers && !self.verbose && !self.settings && self.format.is_none()
+    }
+}
+
+fn postgres_prefix(prefix: &mut String, options: &ExplainOptions) -> Result<(), DialectError> {
+    let format = match options.format {
+        None => None,
+        Some(ExplainFormat::Text) => Some("FORMAT TEXT"),
+        Some(ExplainFormat::Json) => Some("FORMAT JSON"),
+        Some(ExplainFormat::Xml) => Some("FORMAT XML"),
+        Some(ExplainFormat::Yaml) => Some("FORMAT YAML"),
+        Some(ExplainFormat::Tree) => return Err(DialectError::Unsupported("FORMAT TREE")),
+    };
+
+    let items = [
+        (options.analyze, "ANALYZE"),
+        (options.buffers, "BUFFERS"),
+        (options.verbose, "VERBOSE"),
+        (options.settings, "SETTINGS"),
+    ]
+    .into_iter()
+    .filter_map(|(set, option)| set.then_some(option))
+    .chain(format);
+
+    prefix.push_str("EXPLAIN");
+    for (i, item) in items.enumerate() {
+        prefix.push_str(if i == 0 { " (" } else { ", " });
+        prefix.push_str(item);
+    }
+    if !options.is_empty() {
+        prefix.push(')');
+    }
+
+    Ok(())
+}
+
+fn mysql_prefix(prefix: &mut String, options: &ExplainOptions) -> Result<(), DialectError> {
+    if options.buffers {
+        return Err(DialectError::Unsupported("BUFFERS"));
+    }
+    if options.verbose {
+        return Err(DialectError::Unsupported("VERBOSE"));
+    }
+    if options.settings {
+        return Err(DialectError::Unsupported("SETTINGS"));
+    }
+
+    let format = match options.format {
+        None => None,
+        Some(ExplainFormat::Text) => Some("FORMAT=TRADITIONAL"),
+        Some(ExplainFormat::Json) => Some("FORMAT=JSON"),
+        Some(ExplainFormat::Tree) => Some("FORMAT=TREE"),
+        Some(ExplainFormat::Xml) => return Err(DialectError::Unsupported("FORMAT XML")),
+        Some(ExplainFormat::Yaml) => return Err(DialectError::Unsupported("FORMAT YAML")),
+    };
+
+    prefix.push_str("EXPLAIN");
+    if options.analyze {
+        prefix.push_str(" ANALYZE");
+    }
+    if let Some(format) = format {
+        prefix.push(' ');
+        prefix.push_str(format);
+    }
+
+    Ok(())
+}
+
+/// Wraps a built command in a `EXPLAIN` command of the dialect.
+///
+/// The arguments of the command are kept as they are, since the placeholders
+/// do not change.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect, DialectError};
+/// # use sqlstr::expr::{select, from_table, filter_where};
+/// # use sqlstr::explain::{explain, ExplainOptions, ExplainFormat};
+/// # fn main() -> Result<(), DialectError> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// select(&mut sql);
+/// sql.push_cmd(" id");
+/// from_table(&mut sql, "user");
+/// filter_where(&mut sql);
+/// sql.push_cmd(" age > 18");
+///
+/// let options = ExplainOptions::new().analyze().buffers().format(ExplainFormat::Json);
+/// let sql = explain(sql, Dialect::Postgres, &options)?;
+///
+/// assert_eq!(
+///     sql.as_command(),
+///     "EXPLAIN (ANALYZE, BUFFERS, FORMAT JSON) SELECT id FROM user WHERE age > 18"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn explain<Arg>(
+    sql: SqlCommand<Arg>,
+    dialect: Dialect,
+    options: &ExplainOptions,
+) -> Result<SqlCommand<Arg>, DialectError> {
+    let mut command = String::with_capacity(sql.command.len() + 48);
+
+    match dialect {
+        Dialect::Postgres => postgres_prefix(&mut command, options)?,
+        Dialect::MySql => mysql_prefix(&mut command, options)?,
+        Dialect::Sqlite if options.is_empty() => command.push_str("EXPLAIN QUERY PLAN"),
+        Dialect::Sqlite => return Err(DialectError::Unsupported("EXPLAIN options")),
+        Dialect::MsSql => return Err(DialectError::Unsupported("EXPLAIN")),
+    }
+
+    command.push(' ');
+    command.push_str(&sql.command);
+
+    Ok(SqlCommand::new(command, sql.arguments))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::TestArgs;
+
+    fn query() -> SqlCommand<TestArgs> {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        sql.push_cmd("SELECT id FROM user WHERE name = ");
+        sql.push_value("foo").unwrap();
+        sql
+    }
+
+    #[test]
+    fn explain_postgres() {
+        let sql = explain(query(), Dialect::Postgres, &ExplainOptions::new()).unwrap();
+        assert_eq!(
+            sql.as_command(),
+            "EXPLAIN SELECT id FROM user WHERE name = $1"
+        );
+        assert_eq!(sql.arguments.as_str(), "foo;");
+
+        let options = ExplainOptions::new()
+            .analyze()
+            .buffers()
+            .verbose()
+            .settings()
+            .format(ExplainFormat::Json);
+        let sql = explain(query(), Dialect::Postgres, &options).unwrap();
+        assert_eq!(
+            sql.as_command(),
+            "EXPLAIN (ANALYZE, BUFFERS, VERBOSE, SETTINGS, FORMAT JSON) \
+            SELECT id FROM user WHERE name = $1"
+        );
+
+        assert_eq!(
+            explain(
+                query(),
+                Dialect::Postgres,
+                &ExplainOptions::new().format(ExplainFormat::Tree)
+            ),
+            Err(DialectError::Unsupported("FORMAT TREE"))
+        );
+    }
+
+    #[test]
+    fn explain_mysql_sqlite() {
+        let options = ExplainOptions::new().format(ExplainFormat::Json);
+        let sql = explain(query(), Dialect::MySql, &options).unwrap();
+        assert_eq!(
+            sql.as_command(),
+            "EXPLAIN FORMAT=JSON SELECT id FROM user WHERE name = $1"
+        );
+
+        let options = ExplainOptions::new().analyze().format(ExplainFormat::Tree);
+        let sql = explain(query(), Dialect::MySql, &options).unwrap();
+        assert_eq!(
+            sql.as_command(),
+            "EXPLAIN ANALYZE FORMAT=TREE SELECT id FROM user WHERE name = $1"
+        );
+
+        let sql = explain(query(), Dialect::Sqlite, &ExplainOptions::new()).unwrap();
+        assert_eq!(
+            sql.as_command(),
+            "EXPLAIN QUERY PLAN SELECT id FROM user WHERE name = $1"
+        );
+        assert_eq!(
+            explain(query(), Dialect::Sqlite, &ExplainOptions::new().analyze()),
+            Err(DialectError::Unsupported("EXPLAIN options"))
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use serde::Deserialize;
+
+/// Output of a PostgreSQL `EXPLAIN (FORMAT JSON)` command
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ExplainPlan {
+    #[serde(rename = "Plan")]
+    pub plan: PlanNode,
+    /// Only present with `ANALYZE`
+    #[serde(rename = "Planning Time", default)]
+    pub planning_time: Option<f64>,
+    /// Only present with `ANALYZE`
+    #[serde(rename = "Execution Time", default)]
+    pub execution_time: Option<f64>,
+}
+
+/// Node of a PostgreSQL plan
+///
+/// The properties without a field, which depend on the node type and the
+/// options, are kept in `extra`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PlanNode {
+    #[serde(rename = "Node Type")]
+    pub node_type: String,
+    #[serde(rename = "Relation Name", default)]
+    pub relation_name: Option<String>,
+    #[serde(rename = "Alias", default)]
+    pub alias: Option<String>,
+    #[serde(rename = "Index Name", default)]
+    pub index_name: Option<String>,
+    #[serde(rename = "Join Type", default)]
+    pub join_type: Option<String>,
+    #[serde(rename = "Filter", default)]
+    pub filter: Option<String>,
+    #[serde(rename = "Index Cond", default)]
+    pub index_cond: Option<String>,
+    #[serde(rename = "Startup Cost")]
+    pub startup_cost: f64,
+    #[serde(rename = "Total Cost")]
+    pub total_cost: f64,
+    #[serde(rename = "Plan Rows")]
+    pub plan_rows: f64,
+    #[serde(rename = "Plan Width")]
+    pub plan_width: u64,
+    #[serde(rename = "Actual Startup Time", default)]
+    pub actual_startup_time: Option<f64>,
+    #[serde(rename = "Actual Total Time", default)]
+    pub actual_total_time: Option<f64>,
+    #[serde(rename = "Actual Rows", default)]
+    pub actual_rows: Option<f64>,
+    #[serde(rename = "Actual Loops", default)]
+    pub actual_loops: Option<f64>,
+    #[serde(rename = "Plans", default)]
+    pub plans: Vec<PlanNode>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+impl PlanNode {
+    /// Finds the first node of the type, depth-first, including this node.
+    pub fn find(&self, node_type: &str) -> Option<&PlanNode> {
+        if self.node_type == node_type {
+            return Some(self);
+        }
+        self.plans.iter().find_map(|node| node.find(node_type))
+    }
+
+    /// Whether a node of the type is in the plan.
+    pub fn contains(&self, node_type: &str) -> bool {
+        self.find(node_type).is_some()
+    }
+}
+
+/// Deserializes the output of a PostgreSQL `EXPLAIN (FORMAT JSON)` command.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::explain::parse_plan;
+/// let output = r#"[{
+///     "Plan": {
+///         "Node Type": "Index Scan",
+///         "Relation Name": "user",
+///         "Index Name": "user_pkey",
+///         "Startup Cost": 0.29,
+///         "Total Cost": 8.30,
+///         "Plan Rows": 1,
+///         "Plan Width": 40
+///     }
+/// }]"#;
+///
+/// let plan = parse_plan(output).unwrap();
+/// assert!(plan.plan.contains("Index Scan"));
+/// assert_eq!(plan.plan.index_name.as_deref(), Some("user_pkey"));
+/// ```
+pub fn parse_plan(json: &str) -> Result<ExplainPlan, serde_json::Error> {
+    let [plan]: [ExplainPlan; 1] = serde_json::from_str(json)?;
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_analyze_plan() {
+        let output = r#"[
+          {
+            "Plan": {
+              "Node Type": "Hash Join",
+              "Parallel Aware": false,
+              "Join Type": "Inner",
+              "Startup Cost": 1.09,
+              "Total Cost": 2.27,
+              "Plan Rows": 4,
+              "Plan Width": 72,
+              "Actual Startup Time": 0.031,
+              "Actual Total Time": 0.036,
+              "Actual Rows": 4,
+              "Actual Loops": 1,
+              "Hash Cond": "(u.org_id = o.id)",
+              "Plans": [
+                {
+                  "Node Type": "Seq Scan",
+                  "Parent Relationship": "Outer",
+                  "Relation Name": "user",
+                  "Alias": "u",
+                  "Startup Cost": 0.00,
+                  "Total Cost": 1.04,
+                  "Plan Rows": 4,
+                  "Plan Width": 40,
+                  "Filter": "(age > 18)"
+                },
+                {
+                  "Node Type": "Hash",
+                  "Startup Cost": 1.04,
+                  "Total Cost": 1.04,
+                  "Plan Rows": 4,
+                  "Plan Width": 36
+                }
+              ]
+            },
+            "Planning Time": 0.120,
+            "Triggers": [],
+            "Execution Time": 0.061
+          }
+        ]"#;
+
+        let plan = parse_plan(output).unwrap();
+        assert_eq!(plan.planning_time, Some(0.120));
+        assert_eq!(plan.execution_time, Some(0.061));
+        assert_eq!(plan.plan.join_type.as_deref(), Some("Inner"));
+        assert_eq!(plan.plan.actual_rows, Some(4.0));
+        assert_eq!(
+            plan.plan.extra.get("Hash Cond"),
+            Some(&serde_json::Value::from("(u.org_id = o.id)"))
+        );
+
+        let scan = plan.plan.find("Seq Scan").unwrap();
+        assert_eq!(scan.relation_name.as_deref(), Some("user"));
+        assert_eq!(scan.filter.as_deref(), Some("(age > 18)"));
+        assert!(!plan.plan.contains("Index Scan"));
+    }
+}
@@ -8,9 +8,11 @@ mod group;
 mod group_by;
 mod insert;
 mod join;
+mod keyset;
 mod limit;
 mod locking;
 mod order_by;
+mod quote;
 mod returning;
 mod select;
 mod separator;
@@ -28,9 +30,11 @@ pub use group::*;
 pub use group_by::*;
 pub use insert::*;
 pub use join::*;
+pub use keyset::*;
 pub use limit::*;
 pub use locking::*;
 pub use order_by::*;
+pub use quote::*;
 pub use returning::*;
 pub use select::*;
 pub use separator::*;
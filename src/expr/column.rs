@@ -126,7 +126,7 @@ pub const fn column_list<'c>() -> ColumnExprList<'c> {
 }
 
 impl<'c> ColumnExprList<'c> {
-    pub const ALIAS: &str = "AS";
+    pub const ALIAS: &'static str = "AS";
 
     pub const fn new() -> Self {
         Self(Vec::new())
@@ -165,6 +165,12 @@ impl<'c> ColumnExprList<'c> {
     }
 }
 
+impl<'c> Default for ColumnExprList<'c> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'c> From<&ColumnExprList<'c>> for ColumnExpr<'c> {
     fn from(value: &ColumnExprList<'c>) -> Self {
         Self::from_list(value)
@@ -4,6 +4,7 @@
 // https://www.postgresql.org/docs/current/functions.html
 
 pub mod comparison;
+pub mod conditional;
 pub mod math;
 
 use super::{separator, separator_optional};
@@ -1,8 +1,51 @@
+use super::{private::Sealed, BinaryOperator};
 use crate::{
     expr::{separator_optional, Group},
     ArgumentBuffer, SqlExpr, WriteSql,
 };
 
+/// Pattern matching operators
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Pattern {
+    /// `LIKE`
+    Like,
+    /// `NOT LIKE`
+    NotLike,
+    /// Case insensitive `ILIKE`, only in Postgres
+    ILike,
+    /// Case insensitive `NOT ILIKE`, only in Postgres
+    NotILike,
+    /// `SIMILAR TO`, only in Postgres
+    Similar,
+    /// `NOT SIMILAR TO`, only in Postgres
+    NotSimilar,
+}
+
+impl Pattern {
+    pub const fn as_str(&self) -> &'static str {
+        match *self {
+            Self::Like => "LIKE",
+            Self::NotLike => "NOT LIKE",
+            Self::ILike => "ILIKE",
+            Self::NotILike => "NOT ILIKE",
+            Self::Similar => "SIMILAR TO",
+            Self::NotSimilar => "NOT SIMILAR TO",
+        }
+    }
+}
+
+impl Sealed for Pattern {}
+
+impl BinaryOperator for Pattern {
+    fn push_operator<Sql, Arg>(&self, sql: &mut Sql)
+    where
+        Sql: WriteSql<Arg>,
+    {
+        sql.push_cmd(self.as_str())
+    }
+}
+
 pub fn between<Sql, Arg, Lhs, Rhs>(
     sql: &mut Sql,
     lhs: SqlExpr<Lhs>,
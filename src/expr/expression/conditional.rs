@@ -0,0 +1,495 @@
+//! Conditional expressions.
+//!
+//! [Postgres conditional expressions](https://www.postgresql.org/docs/current/functions-conditional.html)
+
+use core::marker::PhantomData;
+
+use crate::{expr::separator_optional, ArgumentBuffer, Dialect, SqlExpr, WriteSql};
+
+/// `CASE` expression guard.
+///
+/// Writes `CASE` when opened and `END` when closed (or dropped). The branches
+/// are written with [Case::when], [Case::then] and [Case::otherwise], and since
+/// the guard is also a [WriteSql] any condition writer can be used inside a
+/// `WHEN` branch.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, sqlexpr, sqlvalue};
+/// # use sqlstr::expr::{update_table, set_update, set_column, lhs_binary_rhs, Cmp};
+/// # use sqlstr::expr::conditional::Case;
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// update_table(&mut sql, "user");
+/// set_update(&mut sql);
+/// set_column(&mut sql, "status");
+///
+/// let mut case = Case::open(&mut sql);
+/// case.when();
+/// lhs_binary_rhs(&mut case, sqlexpr::<u8>("age"), Cmp::Gte, sqlvalue(18))?;
+/// case.then_expr(sqlvalue("adult"))?;
+/// case.otherwise_expr(sqlvalue("minor"))?;
+/// case.close();
+///
+/// assert_eq!(
+///     sql.as_command(),
+///     "UPDATE user SET status = CASE WHEN age >= $1 THEN $2 ELSE $3 END"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub struct Case<'cmd, Sql: WriteSql<Arg>, Arg>(&'cmd mut Sql, PhantomData<Arg>);
+
+impl<'cmd, Sql, Arg> Case<'cmd, Sql, Arg>
+where
+    Sql: WriteSql<Arg>,
+{
+    /// Opens a searched `CASE` expression.
+    pub fn open(sql: &'cmd mut Sql) -> Self {
+        separator_optional(sql);
+        sql.push_cmd("CASE");
+        Self(sql, PhantomData)
+    }
+
+    /// Opens a simple `CASE <operand>` expression, where each `WHEN` value is
+    /// compared with the operand.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use sqlstr::{SqlCommand, Void, sqlexpr, sqlvalue};
+    /// # use sqlstr::expr::conditional::Case;
+    /// # use core::convert::Infallible;
+    /// # fn main() -> Result<(), Infallible> {
+    /// let mut sql: SqlCommand<Void> = SqlCommand::default();
+    ///
+    /// let mut case = Case::with_operand(&mut sql, sqlexpr::<u8>("role"))?;
+    /// case.when_expr(sqlvalue(1))?;
+    /// case.then_expr(sqlexpr::<u8>("'admin'"))?;
+    /// case.when_expr(sqlvalue(2))?;
+    /// case.then_expr(sqlexpr::<u8>("'staff'"))?;
+    /// case.close();
+    ///
+    /// assert_eq!(
+    ///     sql.as_command(),
+    ///     "CASE role WHEN $1 THEN 'admin' WHEN $2 THEN 'staff' END"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_operand<T>(sql: &'cmd mut Sql, operand: SqlExpr<'_, T>) -> Result<Self, Arg::Error>
+    where
+        Arg: ArgumentBuffer<T>,
+    {
+        separator_optional(sql);
+        sql.push_cmd("CASE ");
+        sql.push_expr(operand)?;
+        Ok(Self(sql, PhantomData))
+    }
+
+    /// Starts a `WHEN` branch, the condition must be written afterwards.
+    pub fn when(&mut self) {
+        separator_optional(self.0);
+        self.0.push_cmd("WHEN");
+    }
+
+    /// Writes a `WHEN <expr>` branch.
+    pub fn when_expr<T>(&mut self, expr: SqlExpr<'_, T>) -> Result<(), Arg::Error>
+    where
+        Arg: ArgumentBuffer<T>,
+    {
+        separator_optional(self.0);
+        self.0.push_cmd("WHEN ");
+        self.0.push_expr(expr)
+    }
+
+    /// Starts the `THEN` result of a branch, the result must be written afterwards.
+    pub fn then(&mut self) {
+        separator_optional(self.0);
+        self.0.push_cmd("THEN");
+    }
+
+    /// Writes the `THEN <expr>` result of a branch.
+    pub fn then_expr<T>(&mut self, expr: SqlExpr<'_, T>) -> Result<(), Arg::Error>
+    where
+        Arg: ArgumentBuffer<T>,
+    {
+        separator_optional(self.0);
+        self.0.push_cmd("THEN ");
+        self.0.push_expr(expr)
+    }
+
+    /// Starts the `ELSE` result, the result must be written afterwards.
+    pub fn otherwise(&mut self) {
+        separator_optional(self.0);
+        self.0.push_cmd("ELSE");
+    }
+
+    /// Writes the `ELSE <expr>` result.
+    pub fn otherwise_expr<T>(&mut self, expr: SqlExpr<'_, T>) -> Result<(), Arg::Error>
+    where
+        Arg: ArgumentBuffer<T>,
+    {
+        separator_optional(self.0);
+        self.0.push_cmd("ELSE ");
+        self.0.push_expr(expr)
+    }
+
+    pub fn close(self) {
+        core::mem::drop(self)
+    }
+
+    pub fn sql(&mut self) -> &mut Sql {
+        self.0
+    }
+}
+
+impl<'cmd, Sql, Arg> WriteSql<Arg> for Case<'cmd, Sql, Arg>
+where
+    Sql: WriteSql<Arg>,
+{
+    fn push_expr<T>(&mut self, operand: SqlExpr<'_, T>) -> Result<(), <Arg>::Error>
+    where
+        Arg: ArgumentBuffer<T>,
+    {
+        self.0.push_expr(operand)
+    }
+
+    fn push_value<T>(&mut self, value: T) -> Result<(), <Arg>::Error>
+    where
+        Arg: ArgumentBuffer<T>,
+    {
+        self.0.push_value(value)
+    }
+
+    fn push_cmd(&mut self, expr: &str) {
+        self.0.push_cmd(expr)
+    }
+
+    fn as_command(&self) -> &str {
+        self.0.as_command()
+    }
+}
+
+impl<'cmd, Sql, Arg> Drop for Case<'cmd, Sql, Arg>
+where
+    Sql: WriteSql<Arg>,
+{
+    fn drop(&mut self) {
+        self.0.push_cmd(" END");
+    }
+}
+
+/// Writes the function with the list of arguments, returns `false` without
+/// writing anything if the list is empty.
+fn function_args<'ex, Sql, Arg, I, T>(
+    sql: &mut Sql,
+    name: &str,
+    args: I,
+) -> Result<bool, Arg::Error>
+where
+    Sql: WriteSql<Arg>,
+    Arg: ArgumentBuffer<T>,
+    I: IntoIterator<Item = SqlExpr<'ex, T>>,
+{
+    let mut args = args.into_iter();
+    let Some(first) = args.next() else {
+        return Ok(false);
+    };
+
+    separator_optional(sql);
+    sql.push_cmd(name);
+    sql.push_cmd("(");
+    sql.push_expr(first)?;
+    for arg in args {
+        sql.push_cmd(", ");
+        sql.push_expr(arg)?;
+    }
+
+    sql.push_cmd(")");
+    Ok(true)
+}
+
+/// Writes a `COALESCE(<expr>, ...)` function, that returns the first of its
+/// arguments that is not null.
+///
+/// The function needs at least one expression, returns `false` without
+/// writing anything if `exprs` is empty.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, sqlexpr, sqlvalue};
+/// # use sqlstr::expr::{select, conditional::coalesce};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// select(&mut sql);
+/// coalesce(&mut sql, [sqlexpr("nickname"), sqlexpr("name"), sqlvalue("anonymous")])?;
+///
+/// assert_eq!(sql.as_command(), "SELECT COALESCE(nickname, name, $1)");
+/// # Ok(())
+/// # }
+/// ```
+pub fn coalesce<'ex, Sql, Arg, I, T>(sql: &mut Sql, exprs: I) -> Result<bool, Arg::Error>
+where
+    Sql: WriteSql<Arg>,
+    Arg: ArgumentBuffer<T>,
+    I: IntoIterator<Item = SqlExpr<'ex, T>>,
+{
+    function_args(sql, "COALESCE", exprs)
+}
+
+/// Writes a `NULLIF(<lhs>, <rhs>)` function, that returns null if both
+/// arguments are equal, otherwise `lhs` is returned.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, sqlexpr, sqlvalue};
+/// # use sqlstr::expr::{select, conditional::nullif};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// select(&mut sql);
+/// nullif(&mut sql, sqlexpr::<&str>("status"), sqlvalue("unknown"))?;
+///
+/// assert_eq!(sql.as_command(), "SELECT NULLIF(status, $1)");
+/// # Ok(())
+/// # }
+/// ```
+pub fn nullif<Sql, Arg, Lhs, Rhs>(
+    sql: &mut Sql,
+    lhs: SqlExpr<Lhs>,
+    rhs: SqlExpr<Rhs>,
+) -> Result<(), <Arg as ArgumentBuffer<Lhs>>::Error>
+where
+    Sql: WriteSql<Arg>,
+    Arg: ArgumentBuffer<Lhs>,
+    Arg: ArgumentBuffer<Rhs, Error = <Arg as ArgumentBuffer<Lhs>>::Error>,
+{
+    separator_optional(sql);
+    sql.push_cmd("NULLIF(");
+    sql.push_expr(lhs)?;
+    sql.push_cmd(", ");
+    sql.push_expr(rhs)?;
+    sql.push_cmd(")");
+    Ok(())
+}
+
+/// Writes `name` or, for SQLite, the multi-argument `sqlite_name` function.
+///
+/// SQLite reads `MAX` and `MIN` with a single argument as aggregates, so a
+/// single expression is written in parentheses instead.
+fn extremum<'ex, Sql, Arg, I, T>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    name: &str,
+    sqlite_name: &str,
+    exprs: I,
+) -> Result<bool, Arg::Error>
+where
+    Sql: WriteSql<Arg>,
+    Arg: ArgumentBuffer<T>,
+    I: IntoIterator<Item = SqlExpr<'ex, T>>,
+{
+    if dialect != Dialect::Sqlite {
+        return function_args(sql, name, exprs);
+    }
+
+    let mut exprs = exprs.into_iter().peekable();
+    let Some(first) = exprs.next() else {
+        return Ok(false);
+    };
+    if exprs.peek().is_some() {
+        return function_args(sql, sqlite_name, core::iter::once(first).chain(exprs));
+    }
+
+    separator_optional(sql);
+    sql.push_cmd("(");
+    sql.push_expr(first)?;
+    sql.push_cmd(")");
+    Ok(true)
+}
+
+/// Writes a `GREATEST(<expr>, ...)` function, that returns the largest value
+/// from the list of expressions.
+///
+/// SQLite has no `GREATEST`, the multi-argument `MAX(<expr>, ...)` is written
+/// instead, it returns null if any argument is null.
+///
+/// The function needs at least one expression, returns `false` without
+/// writing anything if `exprs` is empty.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect, sqlexpr, sqlvalue};
+/// # use sqlstr::expr::{select, conditional::greatest};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// select(&mut sql);
+/// greatest(&mut sql, Dialect::Postgres, [sqlexpr("price"), sqlvalue(10)])?;
+///
+/// assert_eq!(sql.as_command(), "SELECT GREATEST(price, $1)");
+/// # Ok(())
+/// # }
+/// ```
+pub fn greatest<'ex, Sql, Arg, I, T>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    exprs: I,
+) -> Result<bool, Arg::Error>
+where
+    Sql: WriteSql<Arg>,
+    Arg: ArgumentBuffer<T>,
+    I: IntoIterator<Item = SqlExpr<'ex, T>>,
+{
+    extremum(sql, dialect, "GREATEST", "MAX", exprs)
+}
+
+/// Writes a `LEAST(<expr>, ...)` function, that returns the smallest value
+/// from the list of expressions.
+///
+/// SQLite has no `LEAST`, the multi-argument `MIN(<expr>, ...)` is written
+/// instead, it returns null if any argument is null.
+///
+/// The function needs at least one expression, returns `false` without
+/// writing anything if `exprs` is empty.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect, sqlexpr, sqlvalue};
+/// # use sqlstr::expr::{select, conditional::least};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// select(&mut sql);
+/// least(&mut sql, Dialect::Sqlite, [sqlexpr("stock"), sqlvalue(100)])?;
+///
+/// assert_eq!(sql.as_command(), "SELECT MIN(stock, $1)");
+/// # Ok(())
+/// # }
+/// ```
+pub fn least<'ex, Sql, Arg, I, T>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    exprs: I,
+) -> Result<bool, Arg::Error>
+where
+    Sql: WriteSql<Arg>,
+    Arg: ArgumentBuffer<T>,
+    I: IntoIterator<Item = SqlExpr<'ex, T>>,
+{
+    extremum(sql, dialect, "LEAST", "MIN", exprs)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        expr::{column, continue_condition, lhs_binary_rhs, select, Cmp, LogicBi},
+        sqlexpr, sqlvalue,
+        test::TestArgs,
+        SqlCommand,
+    };
+
+    #[test]
+    fn searched_case() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        select(&mut sql);
+        column(&mut sql, "id,");
+        {
+            let mut case = Case::open(&mut sql);
+            case.when();
+            lhs_binary_rhs(&mut case, sqlexpr::<i32>("score"), Cmp::Gte, sqlvalue(90)).unwrap();
+            continue_condition(&mut case, LogicBi::And);
+            lhs_binary_rhs(
+                &mut case,
+                sqlexpr::<i32>("active"),
+                Cmp::Eq,
+                sqlexpr::<i32>("true"),
+            )
+            .unwrap();
+            case.then_expr(sqlvalue("gold")).unwrap();
+            case.when();
+            lhs_binary_rhs(&mut case, sqlexpr::<i32>("score"), Cmp::Gte, sqlvalue(50)).unwrap();
+            case.then_expr(sqlvalue("silver")).unwrap();
+            case.otherwise_expr(sqlexpr::<&str>("NULL")).unwrap();
+        }
+        sql.push_cmd(" AS tier");
+
+        assert_eq!(
+            sql.as_command(),
+            "SELECT id, CASE WHEN score >= $1 AND active = true THEN $2 WHEN score >= $3 THEN $4 ELSE NULL END AS tier"
+        );
+        assert_eq!(sql.arguments.as_str(), "90;gold;50;silver;");
+    }
+
+    #[test]
+    fn case_then_with_expression() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        let mut case = Case::with_operand(&mut sql, sqlexpr::<&str>("kind")).unwrap();
+        case.when_expr(sqlvalue("fee")).unwrap();
+        case.then();
+        coalesce(&mut case, [sqlexpr("fee"), sqlvalue(0)]).unwrap();
+        case.otherwise();
+        column(&mut case, "0");
+        case.close();
+
+        assert_eq!(
+            sql.as_command(),
+            "CASE kind WHEN $1 THEN COALESCE(fee, $2) ELSE 0 END"
+        );
+        assert_eq!(sql.arguments.as_str(), "fee;0;");
+    }
+
+    #[test]
+    fn conditional_functions() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        select(&mut sql);
+        greatest(
+            &mut sql,
+            Dialect::MySql,
+            [sqlexpr("a"), sqlvalue(1), sqlexpr("b")],
+        )
+        .unwrap();
+        sql.push_cmd(",");
+        least(&mut sql, Dialect::MsSql, [sqlvalue(2), sqlexpr("c")]).unwrap();
+        sql.push_cmd(",");
+        nullif(&mut sql, sqlexpr::<i32>("d"), sqlvalue(3)).unwrap();
+
+        assert_eq!(
+            sql.as_command(),
+            "SELECT GREATEST(a, $1, b), LEAST($2, c), NULLIF(d, $3)"
+        );
+        assert_eq!(sql.arguments.as_str(), "1;2;3;");
+    }
+
+    #[test]
+    fn empty_function_args() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        select(&mut sql);
+
+        assert!(!coalesce(&mut sql, [] as [SqlExpr<i32>; 0]).unwrap());
+        assert!(!greatest(&mut sql, Dialect::Postgres, [] as [SqlExpr<i32>; 0]).unwrap());
+        assert!(!least(&mut sql, Dialect::Sqlite, [] as [SqlExpr<i32>; 0]).unwrap());
+        assert_eq!(sql.as_command(), "SELECT");
+    }
+
+    #[test]
+    fn sqlite_extremum() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        select(&mut sql);
+        greatest(&mut sql, Dialect::Sqlite, [sqlexpr("a"), sqlvalue(1)]).unwrap();
+        sql.push_cmd(",");
+        least(&mut sql, Dialect::Sqlite, [sqlvalue(2)]).unwrap();
+
+        assert_eq!(sql.as_command(), "SELECT MAX(a, $1), ($2)");
+        assert_eq!(sql.arguments.as_str(), "1;2;");
+    }
+}
@@ -91,7 +91,7 @@ pub enum MathUnary {
 impl MathUnary {
     pub const fn as_str(&self) -> &'static str {
         match *self {
-            Self::Neg => "!",
+            Self::Neg => "-",
             Self::BitNot => "~",
         }
     }
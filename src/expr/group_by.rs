@@ -1,3 +1,67 @@
+#[doc(hidden)]
+#[macro_export]
+macro_rules! static_grouping_set {
+    (()) => {
+        "()"
+    };
+    (($first:literal $(, $column:literal)* $(,)?)) => {
+        concat!("(", $first, $(", ", $column,)* ")")
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! static_grouping_element {
+    ($column:literal) => {
+        $column
+    };
+    ($column:literal, $($rest:tt)+) => {
+        concat!($column, ", ", $crate::expr::static_grouping_element!($($rest)+))
+    };
+
+    (ROLLUP $set:tt) => {
+        concat!("ROLLUP ", $crate::expr::static_grouping_set!($set))
+    };
+    (ROLLUP $set:tt, $($rest:tt)+) => {
+        concat!(
+            "ROLLUP ",
+            $crate::expr::static_grouping_set!($set),
+            ", ",
+            $crate::expr::static_grouping_element!($($rest)+)
+        )
+    };
+
+    (CUBE $set:tt) => {
+        concat!("CUBE ", $crate::expr::static_grouping_set!($set))
+    };
+    (CUBE $set:tt, $($rest:tt)+) => {
+        concat!(
+            "CUBE ",
+            $crate::expr::static_grouping_set!($set),
+            ", ",
+            $crate::expr::static_grouping_element!($($rest)+)
+        )
+    };
+
+    (GROUPING SETS ($fset:tt $(, $set:tt)* $(,)?)) => {
+        concat!(
+            "GROUPING SETS (",
+            $crate::expr::static_grouping_set!($fset),
+            $(", ", $crate::expr::static_grouping_set!($set),)*
+            ")"
+        )
+    };
+    (GROUPING SETS ($fset:tt $(, $set:tt)* $(,)?), $($rest:tt)+) => {
+        concat!(
+            "GROUPING SETS (",
+            $crate::expr::static_grouping_set!($fset),
+            $(", ", $crate::expr::static_grouping_set!($set),)*
+            "), ",
+            $crate::expr::static_grouping_element!($($rest)+)
+        )
+    };
+}
+
 /// Create a valid GROUP BY clause as a `&'static str` at compile-time.
 ///
 /// # Example
@@ -8,18 +72,62 @@
 ///
 /// assert_eq!(GROUP_BY_CLAUSE, "GROUP BY id, customer_id");
 /// ```
+///
+/// Grouping sets are written with `ROLLUP`, `CUBE` and `GROUPING SETS`.
+///
+/// ```
+/// # use sqlstr::expr::static_group_by;
+/// const GROUP_BY_CLAUSE: &str = static_group_by!(
+///     DISTINCT "year", ROLLUP ("region", "city"), GROUPING SETS (("a", "b"), ("a"), ())
+/// );
+///
+/// assert_eq!(
+///     GROUP_BY_CLAUSE,
+///     "GROUP BY DISTINCT year, ROLLUP (region, city), GROUPING SETS ((a, b), (a), ())"
+/// );
+/// ```
 #[macro_export]
 macro_rules! static_group_by {
     ($first:literal$(,)? $($column:literal),* $(,)?) => {
         concat!("GROUP BY ", $first, $(", ", $column),*)
     };
+    (DISTINCT $($element:tt)+) => {
+        concat!("GROUP BY DISTINCT ", $crate::expr::static_grouping_element!($($element)+))
+    };
+    (ALL $($element:tt)+) => {
+        concat!("GROUP BY ALL ", $crate::expr::static_grouping_element!($($element)+))
+    };
+    ($($element:tt)+) => {
+        concat!("GROUP BY ", $crate::expr::static_grouping_element!($($element)+))
+    };
 }
 
 pub use static_group_by;
+#[doc(hidden)]
+pub use static_grouping_element;
+#[doc(hidden)]
+pub use static_grouping_set;
 
 use super::separator_optional;
 use crate::WriteSql;
 
+fn grouping_list<'col, Sql, Arg, I>(sql: &mut Sql, columns: I)
+where
+    Sql: WriteSql<Arg>,
+    I: IntoIterator<Item = &'col str>,
+{
+    sql.push_cmd("(");
+    let mut cols = columns.into_iter();
+    if let Some(col) = cols.next() {
+        sql.push_cmd(col);
+    }
+    for col in cols {
+        sql.push_cmd(", ");
+        sql.push_cmd(col);
+    }
+    sql.push_cmd(")");
+}
+
 /// Writes a `GROUP BY` clause.
 ///
 /// # Example
@@ -55,6 +163,210 @@ where
     }
 }
 
+/// Set quantifier of a `GROUP BY` clause
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum GroupByQuantifier {
+    All,
+    /// Remove duplicated grouping sets
+    Distinct,
+}
+
+impl GroupByQuantifier {
+    pub const fn as_str(&self) -> &'static str {
+        match *self {
+            Self::All => "ALL",
+            Self::Distinct => "DISTINCT",
+        }
+    }
+}
+
+/// Element of a `GROUP BY` clause
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum GroupingElement<'g> {
+    /// `<column>`
+    Column(&'g str),
+    /// `ROLLUP (<column>, ...)`
+    Rollup(&'g [&'g str]),
+    /// `CUBE (<column>, ...)`
+    Cube(&'g [&'g str]),
+    /// `GROUPING SETS ((<column>, ...), ...)`
+    Sets(&'g [&'g [&'g str]]),
+}
+
+/// Writes a `GROUP BY` clause with grouping elements.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, SqlExpr};
+/// # use sqlstr::expr::{group_by_elements, GroupByQuantifier, GroupingElement};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// group_by_elements(
+///     &mut sql,
+///     Some(GroupByQuantifier::Distinct),
+///     [
+///         GroupingElement::Column("year"),
+///         GroupingElement::Rollup(&["region", "city"]),
+///         GroupingElement::Sets(&[&["brand", "size"], &["brand"], &[]]),
+///     ],
+/// );
+///
+/// assert_eq!(
+///     sql.as_command(),
+///     "GROUP BY DISTINCT year, ROLLUP (region, city), GROUPING SETS ((brand, size), (brand), ())"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn group_by_elements<'g, Sql, Arg, I>(
+    sql: &mut Sql,
+    quantifier: Option<GroupByQuantifier>,
+    elements: I,
+) where
+    Sql: WriteSql<Arg>,
+    I: IntoIterator<Item = GroupingElement<'g>>,
+{
+    separator_optional(sql);
+    sql.push_cmd("GROUP BY");
+
+    if let Some(quantifier) = quantifier {
+        sql.push_cmd(" ");
+        sql.push_cmd(quantifier.as_str());
+    }
+
+    let mut elements = elements.into_iter();
+    if let Some(element) = elements.next() {
+        sql.push_cmd(" ");
+        grouping_element(sql, element);
+    }
+    for element in elements {
+        sql.push_cmd(", ");
+        grouping_element(sql, element);
+    }
+}
+
+fn grouping_element<Sql, Arg>(sql: &mut Sql, element: GroupingElement<'_>)
+where
+    Sql: WriteSql<Arg>,
+{
+    match element {
+        GroupingElement::Column(column) => sql.push_cmd(column),
+        GroupingElement::Rollup(columns) => rollup(sql, columns.iter().copied()),
+        GroupingElement::Cube(columns) => cube(sql, columns.iter().copied()),
+        GroupingElement::Sets(sets) => {
+            grouping_sets(sql, sets.iter().map(|set| set.iter().copied()))
+        }
+    }
+}
+
+/// Writes a `ROLLUP (<column>, ...)` grouping element.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, SqlExpr};
+/// # use sqlstr::expr::{group_by, rollup};
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// group_by(&mut sql, []);
+/// rollup(&mut sql, ["region", "city"]);
+///
+/// assert_eq!(sql.as_command(), "GROUP BY ROLLUP (region, city)");
+/// ```
+pub fn rollup<'col, Sql, Arg, I>(sql: &mut Sql, columns: I)
+where
+    Sql: WriteSql<Arg>,
+    I: IntoIterator<Item = &'col str>,
+{
+    separator_optional(sql);
+    sql.push_cmd("ROLLUP ");
+    grouping_list(sql, columns);
+}
+
+/// Writes a `CUBE (<column>, ...)` grouping element.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, SqlExpr};
+/// # use sqlstr::expr::{group_by, cube};
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// group_by(&mut sql, []);
+/// cube(&mut sql, ["brand", "size"]);
+///
+/// assert_eq!(sql.as_command(), "GROUP BY CUBE (brand, size)");
+/// ```
+pub fn cube<'col, Sql, Arg, I>(sql: &mut Sql, columns: I)
+where
+    Sql: WriteSql<Arg>,
+    I: IntoIterator<Item = &'col str>,
+{
+    separator_optional(sql);
+    sql.push_cmd("CUBE ");
+    grouping_list(sql, columns);
+}
+
+/// Writes a `GROUPING SETS ((<column>, ...), ...)` grouping element.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, SqlExpr};
+/// # use sqlstr::expr::{group_by, grouping_sets};
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// group_by(&mut sql, []);
+/// grouping_sets(&mut sql, [vec!["brand", "size"], vec!["brand"], vec![]]);
+///
+/// assert_eq!(sql.as_command(), "GROUP BY GROUPING SETS ((brand, size), (brand), ())");
+/// ```
+pub fn grouping_sets<'col, Sql, Arg, I, S>(sql: &mut Sql, sets: I)
+where
+    Sql: WriteSql<Arg>,
+    I: IntoIterator<Item = S>,
+    S: IntoIterator<Item = &'col str>,
+{
+    separator_optional(sql);
+    sql.push_cmd("GROUPING SETS (");
+
+    let mut sets = sets.into_iter();
+    if let Some(set) = sets.next() {
+        grouping_list(sql, set);
+    }
+    for set in sets {
+        sql.push_cmd(", ");
+        grouping_list(sql, set);
+    }
+
+    sql.push_cmd(")");
+}
+
+/// Writes a `GROUPING(<column>, ...)` function, that returns a bit mask of
+/// the columns not included in the current grouping set.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, SqlExpr};
+/// # use sqlstr::expr::{select, grouping};
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// select(&mut sql);
+/// grouping(&mut sql, ["region", "city"]);
+///
+/// assert_eq!(sql.as_command(), "SELECT GROUPING(region, city)");
+/// ```
+pub fn grouping<'col, Sql, Arg, I>(sql: &mut Sql, columns: I)
+where
+    Sql: WriteSql<Arg>,
+    I: IntoIterator<Item = &'col str>,
+{
+    separator_optional(sql);
+    sql.push_cmd("GROUPING");
+    grouping_list(sql, columns);
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -77,4 +389,48 @@ mod test {
 
         assert_eq!(sql.as_command(), "GROUP BY");
     }
+
+    #[test]
+    fn group_by_grouping_elements() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+
+        group_by_elements(
+            &mut sql,
+            None,
+            [
+                GroupingElement::Cube(&["a", "b"]),
+                GroupingElement::Sets(&[&[]]),
+            ],
+        );
+
+        assert_eq!(sql.as_command(), "GROUP BY CUBE (a, b), GROUPING SETS (())");
+    }
+
+    #[test]
+    fn static_group_by_grouping_sets() {
+        assert_eq!(
+            static_group_by!(ROLLUP("region", "city")),
+            "GROUP BY ROLLUP (region, city)"
+        );
+        assert_eq!(
+            static_group_by!(CUBE("a", "b"), "c"),
+            "GROUP BY CUBE (a, b), c"
+        );
+        assert_eq!(
+            static_group_by!("c", CUBE("a", "b"), ROLLUP("d")),
+            "GROUP BY c, CUBE (a, b), ROLLUP (d)"
+        );
+        assert_eq!(
+            static_group_by!(GROUPING SETS (("a", "b"), ("a"), ())),
+            "GROUP BY GROUPING SETS ((a, b), (a), ())"
+        );
+        assert_eq!(
+            static_group_by!(ALL GROUPING SETS (("a")), "b"),
+            "GROUP BY ALL GROUPING SETS ((a)), b"
+        );
+        assert_eq!(
+            static_group_by!(DISTINCT ROLLUP ("a", "b"), ROLLUP ("a", "c")),
+            "GROUP BY DISTINCT ROLLUP (a, b), ROLLUP (a, c)"
+        );
+    }
 }
@@ -1,11 +1,16 @@
 use crate::WriteSql;
 
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum JoinType {
     Cross,
     Inner,
     Left,
     Right,
     Full,
+    LeftOuter,
+    RightOuter,
+    FullOuter,
 }
 
 impl JoinType {
@@ -16,10 +21,49 @@ impl JoinType {
             Self::Left => "LEFT",
             Self::Right => "RIGHT",
             Self::Full => "FULL",
+            Self::LeftOuter => "LEFT OUTER",
+            Self::RightOuter => "RIGHT OUTER",
+            Self::FullOuter => "FULL OUTER",
         }
     }
 }
 
+/// Type of a `NATURAL` join, a [JoinType] without `CROSS`
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum NaturalJoinType {
+    Inner,
+    Left,
+    Right,
+    Full,
+    LeftOuter,
+    RightOuter,
+    FullOuter,
+}
+
+impl NaturalJoinType {
+    pub const fn as_str(&self) -> &'static str {
+        match *self {
+            Self::Inner => "INNER",
+            Self::Left => "LEFT",
+            Self::Right => "RIGHT",
+            Self::Full => "FULL",
+            Self::LeftOuter => "LEFT OUTER",
+            Self::RightOuter => "RIGHT OUTER",
+            Self::FullOuter => "FULL OUTER",
+        }
+    }
+}
+
+/// Allows a join source to reference columns from the preceding `FROM` items.
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub enum JoinLateral {
+    Lateral,
+    #[default]
+    None,
+}
+
 /// Writes a `CROSS JOIN <table>` clause
 ///
 /// # Example
@@ -75,6 +119,179 @@ where
     }
 }
 
+/// Starts a `NATURAL JOIN` clause, joining the tables on all the columns with
+/// the same name.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, SqlExpr};
+/// # use sqlstr::expr::{natural_join, NaturalJoinType};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// natural_join(&mut sql, NaturalJoinType::LeftOuter, "profile", Some("p"));
+///
+/// assert_eq!(sql.as_command(), "NATURAL LEFT OUTER JOIN profile AS p");
+/// # Ok(())
+/// # }
+/// ```
+pub fn natural_join<Sql, Arg>(sql: &mut Sql, typ: NaturalJoinType, table: &str, alias: Option<&str>)
+where
+    Sql: WriteSql<Arg>,
+{
+    separator_optional(sql);
+    sql.push_cmd("NATURAL ");
+    sql.push_cmd(typ.as_str());
+    sql.push_cmd(" JOIN ");
+    sql.push_cmd(table);
+    if let Some(alias) = alias {
+        sql.push_cmd(" AS ");
+        sql.push_cmd(alias);
+    }
+}
+
+fn join_alias<'c, Sql, Arg, I>(sql: &mut Sql, alias: &str, columns: I)
+where
+    Sql: WriteSql<Arg>,
+    I: IntoIterator<Item = &'c str>,
+{
+    sql.push_cmd(" AS ");
+    sql.push_cmd(alias);
+
+    let mut cols = columns.into_iter();
+    if let Some(col) = cols.next() {
+        sql.push_cmd(" (");
+        sql.push_cmd(col);
+        for col in cols {
+            sql.push_cmd(", ");
+            sql.push_cmd(col);
+        }
+        sql.push_cmd(")");
+    }
+}
+
+/// Starts a `JOIN` clause with a subquery, written by the `subquery` closure,
+/// as the join source.
+///
+/// `<type> JOIN [LATERAL] (<subquery>) AS <alias> [(<column>, ...)]`
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, SqlExpr, sqlexpr, sqlvalue};
+/// # use sqlstr::expr::{
+/// #     join_subquery, join_on_with, select, column, from_table, filter_where,
+/// #     lhs_binary_rhs, limit, Cmp, JoinType, JoinLateral
+/// # };
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// join_subquery(
+///     &mut sql,
+///     JoinType::Left,
+///     JoinLateral::Lateral,
+///     |sql| {
+///         select(sql);
+///         column(sql, "created");
+///         from_table(sql, "post");
+///         filter_where(sql);
+///         lhs_binary_rhs(sql, sqlexpr::<u8>("post.author"), Cmp::Eq, sqlexpr::<i32>("u.id"))?;
+///         limit(sql, 3_i64)
+///     },
+///     "p",
+///     ["post_created"],
+/// )?;
+/// join_on_with(&mut sql, |_| Ok::<_, Infallible>(false))?;
+///
+/// assert_eq!(
+///     sql.as_command(),
+///     "LEFT JOIN LATERAL (SELECT created FROM post WHERE post.author = u.id LIMIT $1) AS p (post_created) ON true"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn join_subquery<'c, Sql, Arg, F, E, I>(
+    sql: &mut Sql,
+    typ: JoinType,
+    lateral: JoinLateral,
+    subquery: F,
+    alias: &str,
+    columns: I,
+) -> Result<(), E>
+where
+    Sql: WriteSql<Arg>,
+    F: FnOnce(&mut Sql) -> Result<(), E>,
+    I: IntoIterator<Item = &'c str>,
+{
+    separator_optional(sql);
+    sql.push_cmd(typ.as_str());
+    sql.push_cmd(" JOIN ");
+    if lateral == JoinLateral::Lateral {
+        sql.push_cmd("LATERAL ");
+    }
+    sql.push_cmd("(");
+    subquery(sql)?;
+    sql.push_cmd(")");
+    join_alias(sql, alias, columns);
+    Ok(())
+}
+
+/// Starts a `JOIN` clause with a table function, written by the `function`
+/// closure, as the join source.
+///
+/// `<type> JOIN [LATERAL] <function> AS <alias> [(<column>, ...)]`
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, SqlExpr};
+/// # use sqlstr::expr::{join_function, JoinType, JoinLateral};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// join_function(
+///     &mut sql,
+///     JoinType::Cross,
+///     JoinLateral::None,
+///     |sql| {
+///         sql.push_cmd("generate_series(1, ");
+///         sql.push_value(10)?;
+///         sql.push_cmd(")");
+///         Ok::<_, Infallible>(())
+///     },
+///     "g",
+///     ["n"],
+/// )?;
+///
+/// assert_eq!(sql.as_command(), "CROSS JOIN generate_series(1, $1) AS g (n)");
+/// # Ok(())
+/// # }
+/// ```
+pub fn join_function<'c, Sql, Arg, F, E, I>(
+    sql: &mut Sql,
+    typ: JoinType,
+    lateral: JoinLateral,
+    function: F,
+    alias: &str,
+    columns: I,
+) -> Result<(), E>
+where
+    Sql: WriteSql<Arg>,
+    F: FnOnce(&mut Sql) -> Result<(), E>,
+    I: IntoIterator<Item = &'c str>,
+{
+    separator_optional(sql);
+    sql.push_cmd(typ.as_str());
+    sql.push_cmd(" JOIN ");
+    if lateral == JoinLateral::Lateral {
+        sql.push_cmd("LATERAL ");
+    }
+    function(sql)?;
+    join_alias(sql, alias, columns);
+    Ok(())
+}
+
 /// Starts a join condition.
 ///
 /// # Example
@@ -100,6 +317,49 @@ where
     sql.push_cmd("ON");
 }
 
+/// Writes a join condition, written by the `condition` closure.
+///
+/// `ON <condition>`
+///
+/// The closure returns if it wrote a condition, otherwise the `ON true`
+/// condition is written.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, SqlExpr, sqlexpr, sqlvalue};
+/// # use sqlstr::expr::{join, join_on_with, lhs_binary_rhs, continue_condition, Cmp, JoinType, LogicBi};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// join(&mut sql, JoinType::Inner, "access", Some("a"));
+/// join_on_with(&mut sql, |cond| {
+///     lhs_binary_rhs(cond, sqlexpr::<u8>("a.user_id"), Cmp::Eq, sqlexpr::<i32>("u.id"))?;
+///     continue_condition(cond, LogicBi::And);
+///     lhs_binary_rhs(cond, sqlexpr::<u8>("a.tenant_id"), Cmp::Eq, sqlvalue(7))?;
+///     Ok::<_, Infallible>(true)
+/// })?;
+///
+/// assert_eq!(
+///     sql.as_command(),
+///     "INNER JOIN access AS a ON a.user_id = u.id AND a.tenant_id = $1"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn join_on_with<Sql, Arg, F, E>(sql: &mut Sql, condition: F) -> Result<(), E>
+where
+    Sql: WriteSql<Arg>,
+    F: FnOnce(&mut Sql) -> Result<bool, E>,
+{
+    join_on(sql);
+    if !condition(sql)? {
+        separator_optional(sql);
+        sql.push_cmd("true");
+    }
+    Ok(())
+}
+
 /// Writes a join condition with the `USING` form.
 ///
 /// # Example
@@ -263,7 +523,75 @@ use super::separator_optional;
 
 #[cfg(test)]
 mod test {
-    use crate::{expr::join_using, test::TestArgs, SqlCommand};
+    use core::fmt;
+
+    use crate::{
+        expr::{
+            column, continue_condition, from_table, join, join_on_with, join_subquery, join_using,
+            lhs_binary_rhs, natural_join, select, Cmp, JoinLateral, JoinType, LogicBi,
+            NaturalJoinType,
+        },
+        sqlexpr, sqlvalue,
+        test::TestArgs,
+        SqlCommand,
+    };
+
+    #[test]
+    fn join_natural() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        from_table(&mut sql, "user");
+        natural_join(&mut sql, NaturalJoinType::Inner, "profile", None);
+        assert_eq!(sql.as_command(), "FROM user NATURAL INNER JOIN profile");
+    }
+
+    #[test]
+    fn join_subquery_bound_condition() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        from_table(&mut sql, "user AS u");
+        join_subquery(
+            &mut sql,
+            JoinType::FullOuter,
+            JoinLateral::None,
+            |sql| {
+                select(sql);
+                column(sql, "user_id, count(*)");
+                from_table(sql, "access");
+                sql.push_cmd(" WHERE");
+                lhs_binary_rhs(sql, sqlexpr::<i32>("tenant"), Cmp::Eq, sqlvalue(3))?;
+                sql.push_cmd(" GROUP BY user_id");
+                Ok::<_, fmt::Error>(())
+            },
+            "a",
+            [],
+        )
+        .unwrap();
+        join_on_with(&mut sql, |cond| {
+            lhs_binary_rhs(
+                cond,
+                sqlexpr::<i32>("a.user_id"),
+                Cmp::Eq,
+                sqlexpr::<i32>("u.id"),
+            )?;
+            continue_condition(cond, LogicBi::And);
+            lhs_binary_rhs(cond, sqlexpr::<i32>("u.tenant"), Cmp::Eq, sqlvalue(3))?;
+            Ok::<_, fmt::Error>(true)
+        })
+        .unwrap();
+
+        assert_eq!(
+            sql.as_command(),
+            "FROM user AS u FULL OUTER JOIN (SELECT user_id, count(*) FROM access WHERE tenant = $1 GROUP BY user_id) AS a ON a.user_id = u.id AND u.tenant = $2"
+        );
+        assert_eq!(sql.arguments.as_str(), "3;3;");
+    }
+
+    #[test]
+    fn join_on_empty_condition() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        join(&mut sql, JoinType::LeftOuter, "tag", None);
+        join_on_with(&mut sql, |_| Ok::<_, fmt::Error>(false)).unwrap();
+        assert_eq!(sql.as_command(), "LEFT OUTER JOIN tag ON true");
+    }
 
     #[test]
     fn join_using_single_column() {
@@ -0,0 +1,758 @@
+//! Keyset (cursor) pagination.
+//!
+//! Instead of skipping rows with `OFFSET`, the next page is selected with a
+//! predicate over the sort keys of the last row returned, which can use an
+//! index and does not get slower on later pages.
+
+use alloc::{string::String, vec::Vec};
+
+use super::{filter_where, limit, order_by, separator_optional, OrderByNulls, OrderByOrd};
+use crate::{format_num::format_u64_base10, ArgumentBuffer, Dialect, WriteSql};
+
+/// Sort key of a keyset pagination: `(column, order, nulls)`.
+///
+/// Same item accepted by [order_by](super::order_by).
+pub type KeysetKey<'k> = (&'k str, OrderByOrd<'k>, OrderByNulls);
+
+fn is_descending(order: &OrderByOrd) -> bool {
+    match order {
+        OrderByOrd::Desc => true,
+        OrderByOrd::Using(op) => op.starts_with('>'),
+        OrderByOrd::Asc | OrderByOrd::Default => false,
+    }
+}
+
+/// Returns if the nulls of a key are sorted after the non-null values.
+fn nulls_after(dialect: Dialect, key: &KeysetKey) -> bool {
+    match key.2 {
+        OrderByNulls::Last => true,
+        OrderByNulls::First => false,
+        // nulls are larger than any non-null value in Postgres, smaller in
+        // the other dialects
+        OrderByNulls::Default => (dialect == Dialect::Postgres) != is_descending(&key.1),
+    }
+}
+
+/// Error while writing a keyset condition.
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum KeysetError<E> {
+    /// Error of the argument buffer
+    Argument(E),
+    /// The count of values is not the count of sort keys, like a cursor of
+    /// another query.
+    Length,
+}
+
+#[cfg(feature = "fmt")]
+impl<E> core::fmt::Display for KeysetError<E>
+where
+    E: core::fmt::Display,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Argument(err) => err.fmt(f),
+            Self::Length => f.write_str("the count of values is not the count of sort keys"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> std::error::Error for KeysetError<E> where E: std::error::Error {}
+
+/// Writes the keyset predicate that selects the rows after `values`, the sort
+/// keys of the last row from the previous page.
+///
+/// When all keys are sorted in the same direction, without nulls ordering and
+/// with non-null values, the row-value form `(a, b) > ($1, $2)` is written,
+/// except for [Dialect::MsSql] that does not compare row values.
+/// Otherwise the predicate is expanded into
+/// `(a > $1 OR (a = $2 AND b < $3))`, pushing a value again for each
+/// comparison of its key.
+///
+/// A key with [OrderByNulls::Default] is assumed to be a non-nullable column
+/// while its value is not null. With [OrderByNulls::First] or
+/// [OrderByNulls::Last] the `NULL` values are considered in the predicate.
+/// The default order of the `NULL` values is the one of the dialect, larger
+/// than any value in Postgres and smaller in MySQL, SQLite and MSSQL.
+///
+/// Returns [KeysetError::Length], without writing anything, if there is not
+/// a value for each key.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect};
+/// # use sqlstr::expr::{keyset_condition, KeysetError, OrderByOrd, OrderByNulls};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), KeysetError<Infallible>> {
+/// let keys = [
+///     ("created", OrderByOrd::Asc, OrderByNulls::Default),
+///     ("id", OrderByOrd::Asc, OrderByNulls::Default),
+/// ];
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// keyset_condition(&mut sql, Dialect::Postgres, &keys, [Some(1702900000), Some(42)])?;
+///
+/// assert_eq!(sql.as_command(), "(created, id) > ($1, $2)");
+///
+/// let keys = [
+///     ("created", OrderByOrd::Desc, OrderByNulls::Default),
+///     ("id", OrderByOrd::Asc, OrderByNulls::Default),
+/// ];
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// keyset_condition(&mut sql, Dialect::Postgres, &keys, [Some(1702900000), Some(42)])?;
+///
+/// assert_eq!(sql.as_command(), "(created < $1 OR (created = $2 AND id > $3))");
+/// # Ok(())
+/// # }
+/// ```
+pub fn keyset_condition<Sql, Arg, I, T>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    keys: &[KeysetKey<'_>],
+    values: I,
+) -> Result<(), KeysetError<Arg::Error>>
+where
+    Sql: WriteSql<Arg>,
+    Arg: ArgumentBuffer<T>,
+    I: IntoIterator<Item = Option<T>>,
+    T: Clone,
+{
+    let values: Vec<Option<T>> = values.into_iter().collect();
+    if values.len() != keys.len() {
+        return Err(KeysetError::Length);
+    }
+
+    write_keyset_condition(sql, dialect, keys, &values).map_err(KeysetError::Argument)
+}
+
+/// Writes the keyset predicate of [keyset_condition], with a value for each
+/// key.
+fn write_keyset_condition<Sql, Arg, T>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    keys: &[KeysetKey<'_>],
+    values: &[Option<T>],
+) -> Result<(), Arg::Error>
+where
+    Sql: WriteSql<Arg>,
+    Arg: ArgumentBuffer<T>,
+    T: Clone,
+{
+    separator_optional(sql);
+
+    if keys.is_empty() {
+        sql.push_cmd("TRUE");
+        return Ok(());
+    }
+
+    let descending = is_descending(&keys[0].1);
+    let row_value = keys.len() > 1
+        && dialect != Dialect::MsSql
+        && values.iter().all(Option::is_some)
+        && keys
+            .iter()
+            .all(|k| k.2 == OrderByNulls::Default && is_descending(&k.1) == descending);
+
+    if row_value {
+        sql.push_cmd("(");
+        sql.push_cmd(keys[0].0);
+        for key in &keys[1..] {
+            sql.push_cmd(", ");
+            sql.push_cmd(key.0);
+        }
+        sql.push_cmd(if descending { ") < (" } else { ") > (" });
+        let mut first = true;
+        for value in values.iter().flatten() {
+            if !first {
+                sql.push_cmd(", ");
+            }
+            first = false;
+            sql.push_value(value.clone())?;
+        }
+        sql.push_cmd(")");
+        return Ok(());
+    }
+
+    sql.push_cmd("(");
+    let mut written = false;
+
+    for (i, (key, value)) in keys.iter().zip(values).enumerate() {
+        // nothing is sorted after a null value when nulls are last
+        if value.is_none() && nulls_after(dialect, key) {
+            continue;
+        }
+
+        if written {
+            sql.push_cmd(" OR ");
+        }
+        written = true;
+        if i > 0 {
+            sql.push_cmd("(");
+        }
+
+        for (prev, prev_value) in keys.iter().zip(values).take(i) {
+            sql.push_cmd(prev.0);
+            match prev_value {
+                Some(prev_value) => {
+                    sql.push_cmd(" = ");
+                    sql.push_value(prev_value.clone())?;
+                }
+                None => sql.push_cmd(" IS NULL"),
+            }
+            sql.push_cmd(" AND ");
+        }
+
+        match value {
+            Some(value) => {
+                let with_nulls = key.2 != OrderByNulls::Default && nulls_after(dialect, key);
+                if with_nulls {
+                    sql.push_cmd("(");
+                }
+                sql.push_cmd(key.0);
+                sql.push_cmd(if is_descending(&key.1) { " < " } else { " > " });
+                sql.push_value(value.clone())?;
+                if with_nulls {
+                    sql.push_cmd(" OR ");
+                    sql.push_cmd(key.0);
+                    sql.push_cmd(" IS NULL)");
+                }
+            }
+            None => {
+                sql.push_cmd(key.0);
+                sql.push_cmd(" IS NOT NULL");
+            }
+        }
+
+        if i > 0 {
+            sql.push_cmd(")");
+        }
+    }
+
+    if !written {
+        sql.push_cmd("FALSE");
+    }
+    sql.push_cmd(")");
+    Ok(())
+}
+
+/// Writes the `ORDER BY` clause of the keyset sort keys.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void};
+/// # use sqlstr::expr::{keyset_order_by, OrderByOrd, OrderByNulls};
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// keyset_order_by(
+///     &mut sql,
+///     &[
+///         ("score", OrderByOrd::Desc, OrderByNulls::Last),
+///         ("id", OrderByOrd::Asc, OrderByNulls::Default),
+///     ],
+/// );
+///
+/// assert_eq!(sql.as_command(), "ORDER BY score DESC NULLS LAST, id ASC");
+/// ```
+pub fn keyset_order_by<Sql, Arg>(sql: &mut Sql, keys: &[KeysetKey<'_>])
+where
+    Sql: WriteSql<Arg>,
+{
+    order_by(sql, keys.iter().cloned());
+}
+
+/// Writes a page of a keyset pagination:
+/// `[WHERE <keyset condition>] ORDER BY <keys> LIMIT <count>`.
+///
+/// The `WHERE` clause is only written when the sort keys of the last row are
+/// provided in `after`, see [keyset_condition]. For queries with other
+/// conditions, use [keyset_condition], [keyset_order_by] and [limit]
+/// instead.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect};
+/// # use sqlstr::expr::{
+/// #     select, column, from_table, keyset_pagination, KeysetError, OrderByOrd, OrderByNulls,
+/// # };
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), KeysetError<Infallible>> {
+/// let keys = [("id", OrderByOrd::Asc, OrderByNulls::Default)];
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// select(&mut sql);
+/// column(&mut sql, "*");
+/// from_table(&mut sql, "user");
+/// keyset_pagination(&mut sql, Dialect::Postgres, &keys, Some([Some(300_i64)]), 20_i64)?;
+///
+/// assert_eq!(
+///     sql.as_command(),
+///     "SELECT * FROM user WHERE (id > $1) ORDER BY id ASC LIMIT $2"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn keyset_pagination<Sql, Arg, I, T, C>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    keys: &[KeysetKey<'_>],
+    after: Option<I>,
+    count: C,
+) -> Result<(), KeysetError<<Arg as ArgumentBuffer<T>>::Error>>
+where
+    Sql: WriteSql<Arg>,
+    Arg: ArgumentBuffer<T>,
+    Arg: ArgumentBuffer<C, Error = <Arg as ArgumentBuffer<T>>::Error>,
+    I: IntoIterator<Item = Option<T>>,
+    T: Clone,
+{
+    if let Some(values) = after {
+        let values: Vec<Option<T>> = values.into_iter().collect();
+        if values.len() != keys.len() {
+            return Err(KeysetError::Length);
+        }
+        filter_where(sql);
+        write_keyset_condition(sql, dialect, keys, &values).map_err(KeysetError::Argument)?;
+    }
+    keyset_order_by(sql, keys);
+    limit(sql, count).map_err(KeysetError::Argument)
+}
+
+/// Value that can be stored in an opaque pagination cursor.
+pub trait CursorValue: Sized {
+    /// Writes the value as text into the buffer.
+    fn encode_cursor(&self, buf: &mut String);
+
+    /// Reads the value from the text written by [CursorValue::encode_cursor].
+    fn decode_cursor(text: &str) -> Option<Self>;
+}
+
+macro_rules! cursor_value_unsigned {
+    ($($ty:ty),+) => {
+        $(
+            impl CursorValue for $ty {
+                fn encode_cursor(&self, buf: &mut String) {
+                    let mut num = [0; 20];
+                    buf.push_str(format_u64_base10(*self as u64, &mut num));
+                }
+
+                fn decode_cursor(text: &str) -> Option<Self> {
+                    text.parse().ok()
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! cursor_value_signed {
+    ($($ty:ty),+) => {
+        $(
+            impl CursorValue for $ty {
+                fn encode_cursor(&self, buf: &mut String) {
+                    let mut num = [0; 20];
+                    if *self < 0 {
+                        buf.push('-');
+                    }
+                    buf.push_str(format_u64_base10(self.unsigned_abs() as u64, &mut num));
+                }
+
+                fn decode_cursor(text: &str) -> Option<Self> {
+                    text.parse().ok()
+                }
+            }
+        )+
+    };
+}
+
+cursor_value_unsigned!(u8, u16, u32, u64, usize);
+cursor_value_signed!(i8, i16, i32, i64, isize);
+
+impl CursorValue for bool {
+    fn encode_cursor(&self, buf: &mut String) {
+        buf.push(if *self { 't' } else { 'f' });
+    }
+
+    fn decode_cursor(text: &str) -> Option<Self> {
+        match text {
+            "t" => Some(true),
+            "f" => Some(false),
+            _ => None,
+        }
+    }
+}
+
+impl CursorValue for String {
+    fn encode_cursor(&self, buf: &mut String) {
+        buf.push_str(self);
+    }
+
+    fn decode_cursor(text: &str) -> Option<Self> {
+        Some(String::from(text))
+    }
+}
+
+impl<T: CursorValue> CursorValue for Option<T> {
+    fn encode_cursor(&self, buf: &mut String) {
+        match self {
+            Some(value) => {
+                buf.push('s');
+                value.encode_cursor(buf);
+            }
+            None => buf.push('n'),
+        }
+    }
+
+    fn decode_cursor(text: &str) -> Option<Self> {
+        if text == "n" {
+            return Some(None);
+        }
+        T::decode_cursor(text.strip_prefix('s')?).map(Some)
+    }
+}
+
+/// Error while decoding a pagination cursor.
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CursorError {
+    /// The cursor is not a valid encoded cursor.
+    Encoding,
+    /// A value of the cursor could not be decoded.
+    Value,
+}
+
+const BASE64_URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+fn base64_url_encode(bytes: &[u8], buf: &mut String) {
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..=chunk.len() {
+            buf.push(BASE64_URL[(n >> (18 - 6 * i) & 0x3F) as usize] as char);
+        }
+    }
+}
+
+fn base64_url_decode(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(text.len() * 3 / 4);
+    for chunk in text.as_bytes().chunks(4) {
+        if chunk.len() == 1 {
+            return None;
+        }
+        let mut n = 0_u32;
+        for (i, ch) in chunk.iter().enumerate() {
+            let sextet = BASE64_URL.iter().position(|c| c == ch)? as u32;
+            n |= sextet << (18 - 6 * i);
+        }
+        for i in 0..chunk.len() - 1 {
+            bytes.push((n >> (16 - 8 * i)) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+/// Encodes the sort keys of the last row of a page into an opaque and URL safe
+/// cursor.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::expr::{encode_cursor, decode_cursor};
+/// let cursor = encode_cursor([Some(1702900000_i64), None, Some(-7)]);
+///
+/// assert_eq!(
+///     decode_cursor::<Option<i64>>(&cursor),
+///     Ok(vec![Some(1702900000), None, Some(-7)])
+/// );
+/// ```
+pub fn encode_cursor<I, T>(values: I) -> String
+where
+    I: IntoIterator<Item = T>,
+    T: CursorValue,
+{
+    let mut payload = String::new();
+    let mut text = String::new();
+    let mut num = [0; 20];
+    for value in values {
+        text.clear();
+        value.encode_cursor(&mut text);
+        payload.push_str(format_u64_base10(text.len() as u64, &mut num));
+        payload.push(':');
+        payload.push_str(&text);
+    }
+
+    let mut cursor = String::with_capacity(payload.len() * 4 / 3 + 4);
+    base64_url_encode(payload.as_bytes(), &mut cursor);
+    cursor
+}
+
+/// Decodes a cursor created with [encode_cursor] into the sort key values.
+pub fn decode_cursor<T>(cursor: &str) -> Result<Vec<T>, CursorError>
+where
+    T: CursorValue,
+{
+    let payload = base64_url_decode(cursor).ok_or(CursorError::Encoding)?;
+    let mut payload = core::str::from_utf8(&payload).map_err(|_| CursorError::Encoding)?;
+
+    let mut values = Vec::new();
+    while !payload.is_empty() {
+        let (len, rest) = payload.split_once(':').ok_or(CursorError::Encoding)?;
+        let len: usize = len.parse().map_err(|_| CursorError::Encoding)?;
+        let text = rest.get(..len).ok_or(CursorError::Encoding)?;
+        values.push(T::decode_cursor(text).ok_or(CursorError::Value)?);
+        payload = &rest[len..];
+    }
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{string::String, vec};
+
+    use super::*;
+    use crate::{
+        expr::{column, continue_condition, from_table, lhs_binary_rhs, select, Cmp, LogicBi},
+        sqlexpr, sqlvalue,
+        test::TestArgs,
+        SqlCommand,
+    };
+
+    #[test]
+    fn keyset_single_key() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        keyset_condition(
+            &mut sql,
+            Dialect::Postgres,
+            &[("id", OrderByOrd::Desc, OrderByNulls::Default)],
+            [Some(10)],
+        )
+        .unwrap();
+
+        assert_eq!(sql.as_command(), "(id < $1)");
+        assert_eq!(sql.arguments.as_str(), "10;");
+    }
+
+    #[test]
+    fn keyset_row_value_descending() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        keyset_condition(
+            &mut sql,
+            Dialect::Postgres,
+            &[
+                ("created", OrderByOrd::Desc, OrderByNulls::Default),
+                ("id", OrderByOrd::Desc, OrderByNulls::Default),
+            ],
+            [Some(2024), Some(7)],
+        )
+        .unwrap();
+
+        assert_eq!(sql.as_command(), "(created, id) < ($1, $2)");
+        assert_eq!(sql.arguments.as_str(), "2024;7;");
+    }
+
+    #[test]
+    fn keyset_expanded_mixed_order() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        keyset_condition(
+            &mut sql,
+            Dialect::Postgres,
+            &[
+                ("a", OrderByOrd::Asc, OrderByNulls::Default),
+                ("b", OrderByOrd::Desc, OrderByNulls::Default),
+                ("c", OrderByOrd::Asc, OrderByNulls::Default),
+            ],
+            [Some(1), Some(2), Some(3)],
+        )
+        .unwrap();
+
+        assert_eq!(
+            sql.as_command(),
+            "(a > $1 OR (a = $2 AND b < $3) OR (a = $4 AND b = $5 AND c > $6))"
+        );
+        assert_eq!(sql.arguments.as_str(), "1;1;2;1;2;3;");
+    }
+
+    #[test]
+    fn keyset_nulls_ordering() {
+        let keys = [
+            ("score", OrderByOrd::Asc, OrderByNulls::Last),
+            ("id", OrderByOrd::Asc, OrderByNulls::Default),
+        ];
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        keyset_condition(&mut sql, Dialect::Postgres, &keys, [Some(50), Some(9)]).unwrap();
+        assert_eq!(
+            sql.as_command(),
+            "((score > $1 OR score IS NULL) OR (score = $2 AND id > $3))"
+        );
+        assert_eq!(sql.arguments.as_str(), "50;50;9;");
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        keyset_condition(&mut sql, Dialect::Postgres, &keys, [None, Some(9)]).unwrap();
+        assert_eq!(sql.as_command(), "((score IS NULL AND id > $1))");
+        assert_eq!(sql.arguments.as_str(), "9;");
+
+        let keys = [
+            ("score", OrderByOrd::Desc, OrderByNulls::First),
+            ("id", OrderByOrd::Asc, OrderByNulls::Default),
+        ];
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        keyset_condition(&mut sql, Dialect::Postgres, &keys, [None, Some(9)]).unwrap();
+        assert_eq!(
+            sql.as_command(),
+            "(score IS NOT NULL OR (score IS NULL AND id > $1))"
+        );
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        keyset_condition(&mut sql, Dialect::Postgres, &keys, [Some(3), Some(9)]).unwrap();
+        assert_eq!(sql.as_command(), "(score < $1 OR (score = $2 AND id > $3))");
+    }
+
+    #[test]
+    fn keyset_default_nulls_dialects() {
+        let keys = [
+            ("score", OrderByOrd::Asc, OrderByNulls::Default),
+            ("id", OrderByOrd::Asc, OrderByNulls::Default),
+        ];
+
+        // nulls are sorted first in ascending order
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        keyset_condition(&mut sql, Dialect::MySql, &keys, [None, Some(9)]).unwrap();
+        assert_eq!(
+            sql.as_command(),
+            "(score IS NOT NULL OR (score IS NULL AND id > $1))"
+        );
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        keyset_condition(&mut sql, Dialect::Postgres, &keys, [None, Some(9)]).unwrap();
+        assert_eq!(sql.as_command(), "((score IS NULL AND id > $1))");
+
+        // no row values in MSSQL
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        keyset_condition(&mut sql, Dialect::MsSql, &keys, [Some(3), Some(9)]).unwrap();
+        assert_eq!(sql.as_command(), "(score > $1 OR (score = $2 AND id > $3))");
+    }
+
+    #[test]
+    fn keyset_after_last_null() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        keyset_condition(
+            &mut sql,
+            Dialect::Postgres,
+            &[("deleted", OrderByOrd::Asc, OrderByNulls::Last)],
+            [None::<i32>],
+        )
+        .unwrap();
+
+        assert_eq!(sql.as_command(), "(FALSE)");
+    }
+
+    #[test]
+    fn keyset_values_length() {
+        let keys = [
+            ("created", OrderByOrd::Desc, OrderByNulls::Default),
+            ("id", OrderByOrd::Asc, OrderByNulls::Default),
+        ];
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        assert_eq!(
+            keyset_condition(&mut sql, Dialect::Postgres, &keys, [Some(1)]),
+            Err(KeysetError::Length)
+        );
+        assert_eq!(
+            keyset_pagination(
+                &mut sql,
+                Dialect::Postgres,
+                &keys,
+                Some([Some(1), Some(2), Some(3)]),
+                10
+            ),
+            Err(KeysetError::Length)
+        );
+        assert_eq!(sql.as_command(), "");
+    }
+
+    #[test]
+    fn keyset_page_with_filter() {
+        let keys = [
+            ("created", OrderByOrd::Desc, OrderByNulls::Default),
+            ("id", OrderByOrd::Asc, OrderByNulls::Default),
+        ];
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        select(&mut sql);
+        column(&mut sql, "id");
+        from_table(&mut sql, "post");
+        sql.push_cmd(" WHERE");
+        lhs_binary_rhs(&mut sql, sqlexpr::<i32>("author"), Cmp::Eq, sqlvalue(5)).unwrap();
+        continue_condition(&mut sql, LogicBi::And);
+        keyset_condition(&mut sql, Dialect::Postgres, &keys, [Some(100), Some(8)]).unwrap();
+        keyset_order_by(&mut sql, &keys);
+        limit(&mut sql, 10).unwrap();
+
+        assert_eq!(
+            sql.as_command(),
+            "SELECT id FROM post WHERE author = $1 AND (created < $2 OR (created = $3 AND id > $4)) ORDER BY created DESC, id ASC LIMIT $5"
+        );
+        assert_eq!(sql.arguments.as_str(), "5;100;100;8;10;");
+    }
+
+    #[test]
+    fn keyset_first_page() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        keyset_pagination(
+            &mut sql,
+            Dialect::Postgres,
+            &[("id", OrderByOrd::Default, OrderByNulls::Default)],
+            None::<[Option<usize>; 1]>,
+            15,
+        )
+        .unwrap();
+
+        assert_eq!(sql.as_command(), "ORDER BY id LIMIT $1");
+        assert_eq!(sql.arguments.as_str(), "15;");
+    }
+
+    #[test]
+    fn cursor_roundtrip() {
+        let cursor = encode_cursor([Some(String::from("crab:rust")), None, Some(String::new())]);
+        assert!(cursor
+            .bytes()
+            .all(|c| c.is_ascii_alphanumeric() || c == b'-' || c == b'_'));
+        assert_eq!(
+            decode_cursor::<Option<String>>(&cursor),
+            Ok(vec![
+                Some(String::from("crab:rust")),
+                None,
+                Some(String::new())
+            ])
+        );
+
+        let cursor = encode_cursor([i64::MIN, 0, i64::MAX]);
+        assert_eq!(decode_cursor(&cursor), Ok(vec![i64::MIN, 0, i64::MAX]));
+
+        assert_eq!(
+            decode_cursor::<bool>(&encode_cursor([true, false])),
+            Ok(vec![true, false])
+        );
+        assert_eq!(decode_cursor::<u8>(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn cursor_invalid() {
+        assert_eq!(decode_cursor::<i32>("%%%"), Err(CursorError::Encoding));
+        assert_eq!(decode_cursor::<i32>("A"), Err(CursorError::Encoding));
+        assert_eq!(
+            decode_cursor::<i32>(&encode_cursor([String::from("x")])),
+            Err(CursorError::Value)
+        );
+    }
+}
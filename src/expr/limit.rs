@@ -1,5 +1,5 @@
 use super::separator_optional;
-use crate::{ArgumentBuffer, WriteSql};
+use crate::{ArgumentBuffer, Dialect, SqlExpr, WriteSql};
 
 /// Writes a LIMIT clause into a sql writer.
 ///
@@ -12,22 +12,69 @@ use crate::{ArgumentBuffer, WriteSql};
 /// # use core::convert::Infallible;
 /// # fn main() -> Result<(), Infallible> {
 /// let mut sql: SqlCommand<Void> = SqlCommand::default();
-/// limit(&mut sql, 10);
+/// limit(&mut sql, 10_i64)?;
 ///
 /// assert_eq!(sql.as_command(), "LIMIT $1");
 /// # Ok(())
 /// # }
 /// ```
-pub fn limit<Sql, Arg>(sql: &mut Sql, count: usize) -> Result<(), Arg::Error>
+pub fn limit<Sql, Arg, T>(sql: &mut Sql, count: T) -> Result<(), Arg::Error>
 where
     Sql: WriteSql<Arg>,
-    Arg: ArgumentBuffer<usize>,
+    Arg: ArgumentBuffer<T>,
 {
     separator_optional(sql);
     sql.push_cmd("LIMIT ");
     sql.push_value(count)
 }
 
+/// Writes a LIMIT clause with an expression into a sql writer.
+///
+/// `LIMIT <expr>`
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, SqlExpr, sqlexpr, expr::limit_expr};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// limit_expr(&mut sql, sqlexpr::<u32>("20"))?;
+///
+/// assert_eq!(sql.as_command(), "LIMIT 20");
+/// # Ok(())
+/// # }
+/// ```
+pub fn limit_expr<Sql, Arg, T>(sql: &mut Sql, count: SqlExpr<'_, T>) -> Result<(), Arg::Error>
+where
+    Sql: WriteSql<Arg>,
+    Arg: ArgumentBuffer<T>,
+{
+    separator_optional(sql);
+    sql.push_cmd("LIMIT ");
+    sql.push_expr(count)
+}
+
+/// Writes a `LIMIT ALL` clause into a sql writer, which is the same as
+/// omitting the LIMIT clause.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, SqlExpr, expr::limit_all};
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// limit_all(&mut sql);
+///
+/// assert_eq!(sql.as_command(), "LIMIT ALL");
+/// ```
+pub fn limit_all<Sql, Arg>(sql: &mut Sql)
+where
+    Sql: WriteSql<Arg>,
+{
+    separator_optional(sql);
+    sql.push_cmd("LIMIT ALL");
+}
+
 /// Writes a offset clause into a sql writer.
 ///
 /// `OFFSET <start>`
@@ -39,22 +86,278 @@ where
 /// # use core::convert::Infallible;
 /// # fn main() -> Result<(), Infallible> {
 /// let mut sql: SqlCommand<Void> = SqlCommand::default();
-/// offset(&mut sql, 30);
+/// offset(&mut sql, 30_i64)?;
 ///
 /// assert_eq!(sql.as_command(), "OFFSET $1");
 /// # Ok(())
 /// # }
 /// ```
-pub fn offset<Sql, Arg>(sql: &mut Sql, start: usize) -> Result<(), Arg::Error>
+pub fn offset<Sql, Arg, T>(sql: &mut Sql, start: T) -> Result<(), Arg::Error>
 where
     Sql: WriteSql<Arg>,
-    Arg: ArgumentBuffer<usize>,
+    Arg: ArgumentBuffer<T>,
 {
     separator_optional(sql);
     sql.push_cmd("OFFSET ");
     sql.push_value(start)
 }
 
+/// Writes a offset clause with an expression into a sql writer.
+///
+/// `OFFSET <expr>`
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, SqlExpr, sqlexpr, expr::offset_expr};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// offset_expr(&mut sql, sqlexpr::<u32>("100"))?;
+///
+/// assert_eq!(sql.as_command(), "OFFSET 100");
+/// # Ok(())
+/// # }
+/// ```
+pub fn offset_expr<Sql, Arg, T>(sql: &mut Sql, start: SqlExpr<'_, T>) -> Result<(), Arg::Error>
+where
+    Sql: WriteSql<Arg>,
+    Arg: ArgumentBuffer<T>,
+{
+    separator_optional(sql);
+    sql.push_cmd("OFFSET ");
+    sql.push_expr(start)
+}
+
+/// Writes the SQL standard offset clause into a sql writer.
+///
+/// `OFFSET <start> ROWS`
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, SqlExpr, sqlvalue, expr::offset_rows};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// offset_rows(&mut sql, sqlvalue(40_i64))?;
+///
+/// assert_eq!(sql.as_command(), "OFFSET $1 ROWS");
+/// # Ok(())
+/// # }
+/// ```
+pub fn offset_rows<Sql, Arg, T>(sql: &mut Sql, start: SqlExpr<'_, T>) -> Result<(), Arg::Error>
+where
+    Sql: WriteSql<Arg>,
+    Arg: ArgumentBuffer<T>,
+{
+    offset_expr(sql, start)?;
+    sql.push_cmd(" ROWS");
+    Ok(())
+}
+
+/// Rows returned beyond the fetch count
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub enum FetchRows {
+    /// Return only the fetch count
+    #[default]
+    Only,
+    /// Also return the rows that tie for the last place, according to the
+    /// `ORDER BY` clause
+    WithTies,
+}
+
+impl FetchRows {
+    pub const fn as_str(&self) -> &'static str {
+        match *self {
+            Self::Only => "ONLY",
+            Self::WithTies => "WITH TIES",
+        }
+    }
+}
+
+/// Writes the SQL standard fetch clause into a sql writer.
+///
+/// `FETCH FIRST <count> ROWS { ONLY | WITH TIES }`
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, SqlExpr, sqlvalue};
+/// # use sqlstr::expr::{offset_rows, fetch_first, FetchRows};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// offset_rows(&mut sql, sqlvalue(20_i64))?;
+/// fetch_first(&mut sql, sqlvalue(10_i64), FetchRows::WithTies)?;
+///
+/// assert_eq!(sql.as_command(), "OFFSET $1 ROWS FETCH FIRST $2 ROWS WITH TIES");
+/// # Ok(())
+/// # }
+/// ```
+pub fn fetch_first<Sql, Arg, T>(
+    sql: &mut Sql,
+    count: SqlExpr<'_, T>,
+    rows: FetchRows,
+) -> Result<(), Arg::Error>
+where
+    Sql: WriteSql<Arg>,
+    Arg: ArgumentBuffer<T>,
+{
+    separator_optional(sql);
+    sql.push_cmd("FETCH FIRST ");
+    sql.push_expr(count)?;
+    sql.push_cmd(" ROWS ");
+    sql.push_cmd(rows.as_str());
+    Ok(())
+}
+
+/// Writes a `TOP` clause into a sql writer, used by MSSQL to limit the rows
+/// right after the `SELECT` keyword.
+///
+/// `TOP (<count>) [WITH TIES]`
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, SqlExpr, sqlvalue};
+/// # use sqlstr::expr::{select, top, FetchRows};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// select(&mut sql);
+/// top(&mut sql, sqlvalue(5_i64), FetchRows::Only)?;
+///
+/// assert_eq!(sql.as_command(), "SELECT TOP ($1)");
+/// # Ok(())
+/// # }
+/// ```
+pub fn top<Sql, Arg, T>(
+    sql: &mut Sql,
+    count: SqlExpr<'_, T>,
+    rows: FetchRows,
+) -> Result<(), Arg::Error>
+where
+    Sql: WriteSql<Arg>,
+    Arg: ArgumentBuffer<T>,
+{
+    separator_optional(sql);
+    sql.push_cmd("TOP (");
+    sql.push_expr(count)?;
+    sql.push_cmd(")");
+    if rows == FetchRows::WithTies {
+        sql.push_cmd(" WITH TIES");
+    }
+    Ok(())
+}
+
+/// Writes a `SELECT` query limited to `count` rows, in the syntax of the
+/// dialect.
+///
+/// The rest of the query, from the select list to the `ORDER BY` clause, is
+/// written by `query`.
+///
+/// - Postgres, MySQL and SQLite: `SELECT <query> LIMIT <count>`
+/// - MSSQL: `SELECT TOP (<count>) <query>`
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, SqlExpr, Dialect, sqlvalue};
+/// # use sqlstr::expr::{select_limit, column, from_table};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// for (dialect, expected) in [
+///     (Dialect::Postgres, "SELECT id FROM user LIMIT $1"),
+///     (Dialect::MsSql, "SELECT TOP ($1) id FROM user"),
+/// ] {
+///     let mut sql: SqlCommand<Void> = SqlCommand::default();
+///     select_limit(&mut sql, dialect, sqlvalue(10_i64), |sql| {
+///         column(sql, "id");
+///         from_table(sql, "user");
+///         Ok(())
+///     })?;
+///
+///     assert_eq!(sql.as_command(), expected);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn select_limit<Sql, Arg, T, F>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    count: SqlExpr<'_, T>,
+    query: F,
+) -> Result<(), Arg::Error>
+where
+    Sql: WriteSql<Arg>,
+    Arg: ArgumentBuffer<T>,
+    F: FnOnce(&mut Sql) -> Result<(), Arg::Error>,
+{
+    separator_optional(sql);
+    sql.push_cmd("SELECT");
+    match dialect {
+        Dialect::Postgres | Dialect::MySql | Dialect::Sqlite => {
+            query(sql)?;
+            limit_expr(sql, count)
+        }
+        Dialect::MsSql => {
+            top(sql, count, FetchRows::Only)?;
+            query(sql)
+        }
+    }
+}
+
+/// Writes a page of `count` rows starting at the row `start`, in the syntax of
+/// the dialect.
+///
+/// - Postgres, MySQL and SQLite: `LIMIT <count> OFFSET <start>`
+/// - MSSQL: `OFFSET <start> ROWS FETCH NEXT <count> ROWS ONLY`
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, SqlExpr, Dialect, sqlvalue};
+/// # use sqlstr::expr::paginate;
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// paginate(&mut sql, Dialect::Sqlite, sqlvalue(10_i64), sqlvalue(30_i64))?;
+/// assert_eq!(sql.as_command(), "LIMIT $1 OFFSET $2");
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// paginate(&mut sql, Dialect::MsSql, sqlvalue(10_i64), sqlvalue(30_i64))?;
+/// assert_eq!(sql.as_command(), "OFFSET $1 ROWS FETCH NEXT $2 ROWS ONLY");
+/// # Ok(())
+/// # }
+/// ```
+pub fn paginate<Sql, Arg, C, S>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    count: SqlExpr<'_, C>,
+    start: SqlExpr<'_, S>,
+) -> Result<(), <Arg as ArgumentBuffer<C>>::Error>
+where
+    Sql: WriteSql<Arg>,
+    Arg: ArgumentBuffer<C>,
+    Arg: ArgumentBuffer<S, Error = <Arg as ArgumentBuffer<C>>::Error>,
+{
+    match dialect {
+        Dialect::Postgres | Dialect::MySql | Dialect::Sqlite => {
+            limit_expr(sql, count)?;
+            offset_expr(sql, start)
+        }
+        Dialect::MsSql => {
+            offset_rows(sql, start)?;
+            sql.push_cmd(" FETCH NEXT ");
+            sql.push_expr(count)?;
+            sql.push_cmd(" ROWS ONLY");
+            Ok(())
+        }
+    }
+}
+
 #[macro_export]
 macro_rules! static_limit {
     (LIMIT $count:literal) => {
@@ -75,6 +378,69 @@ pub use static_offset;
 
 #[cfg(test)]
 mod test {
+    use super::*;
+    use crate::{sqlexpr, sqlvalue, test::TestArgs, SqlCommand};
+
+    #[test]
+    fn limit_integer_types() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        limit(&mut sql, 10_i64).unwrap();
+        offset(&mut sql, 5_u16).unwrap();
+
+        assert_eq!(sql.as_command(), "LIMIT $1 OFFSET $2");
+        assert_eq!(sql.arguments.as_str(), "10;5;");
+    }
+
+    #[test]
+    fn limit_all_offset_literal() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        limit_all(&mut sql);
+        offset_expr(&mut sql, sqlexpr::<i32>("10")).unwrap();
+
+        assert_eq!(sql.as_command(), "LIMIT ALL OFFSET 10");
+        assert_eq!(sql.arguments.as_str(), "");
+    }
+
+    #[test]
+    fn offset_fetch_first() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        offset_rows(&mut sql, sqlvalue(10)).unwrap();
+        fetch_first(&mut sql, sqlexpr::<i32>("5"), FetchRows::Only).unwrap();
+
+        assert_eq!(sql.as_command(), "OFFSET $1 ROWS FETCH FIRST 5 ROWS ONLY");
+        assert_eq!(sql.arguments.as_str(), "10;");
+    }
+
+    #[test]
+    fn select_limit_dialects() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        select_limit(&mut sql, Dialect::MySql, sqlvalue(3), |sql| {
+            sql.push_cmd(" 1");
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(sql.as_command(), "SELECT 1 LIMIT $1");
+        assert_eq!(sql.arguments.as_str(), "3;");
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        select_limit(&mut sql, Dialect::MsSql, sqlvalue(3), |sql| {
+            sql.push_cmd(" 1");
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(sql.as_command(), "SELECT TOP ($1) 1");
+        assert_eq!(sql.arguments.as_str(), "3;");
+    }
+
+    #[test]
+    fn top_with_ties() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        sql.push_cmd("SELECT");
+        top(&mut sql, sqlvalue(3), FetchRows::WithTies).unwrap();
+        assert_eq!(sql.as_command(), "SELECT TOP ($1) WITH TIES");
+        assert_eq!(sql.arguments.as_str(), "3;");
+    }
+
     #[test]
     fn static_limit_macro() {
         assert_eq!(static_limit!(LIMIT 15), "LIMIT 15");
@@ -1,6 +1,7 @@
 use super::separator_optional;
 use crate::WriteSql;
 
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
 #[derive(Clone, Default, PartialEq, Eq)]
 pub enum OrderByOrd<'expr> {
     Asc,
@@ -11,6 +12,7 @@ pub enum OrderByOrd<'expr> {
     Default,
 }
 
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
 #[derive(Clone, Default, PartialEq, Eq)]
 pub enum OrderByNulls {
     First,
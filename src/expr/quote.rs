@@ -0,0 +1,83 @@
+use crate::WriteSql;
+
+fn quoted<Sql, Arg>(sql: &mut Sql, value: &str, quote: char, escaped: &str)
+where
+    Sql: WriteSql<Arg>,
+{
+    let mut buf = [0; 4];
+    let quote = &*quote.encode_utf8(&mut buf);
+
+    sql.push_cmd(quote);
+    let mut parts = value.split(quote);
+    if let Some(part) = parts.next() {
+        sql.push_cmd(part);
+    }
+    for part in parts {
+        sql.push_cmd(escaped);
+        sql.push_cmd(part);
+    }
+    sql.push_cmd(quote);
+}
+
+/// Writes a quoted identifier, doubling the quotes inside it.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void};
+/// # use sqlstr::expr::quote_identifier;
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// quote_identifier(&mut sql, "cache \"users\"");
+///
+/// assert_eq!(sql.as_command(), "\"cache \"\"users\"\"\"");
+/// ```
+pub fn quote_identifier<Sql, Arg>(sql: &mut Sql, ident: &str)
+where
+    Sql: WriteSql<Arg>,
+{
+    quoted(sql, ident, '"', "\"\"");
+}
+
+/// Writes a string literal, doubling the single quotes inside it.
+///
+/// Backslashes are written as they are, which is only safe with
+/// `standard_conforming_strings` enabled in PostgreSQL (the default) or
+/// `NO_BACKSLASH_ESCAPES` in MySQL. Prefer arguments whenever the command
+/// accepts them.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void};
+/// # use sqlstr::expr::quote_literal;
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// quote_literal(&mut sql, "it's");
+///
+/// assert_eq!(sql.as_command(), "'it''s'");
+/// ```
+pub fn quote_literal<Sql, Arg>(sql: &mut Sql, value: &str)
+where
+    Sql: WriteSql<Arg>,
+{
+    quoted(sql, value, '\'', "''");
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::TestArgs, SqlCommand};
+
+    #[test]
+    fn quote_edges() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        quote_literal(&mut sql, "");
+        sql.push_cmd(" ");
+        quote_literal(&mut sql, "''");
+        sql.push_cmd(" ");
+        quote_identifier(&mut sql, "");
+        sql.push_cmd(" ");
+        quote_identifier(&mut sql, "a'b");
+
+        assert_eq!(sql.as_command(), "'' '''''' \"\" \"a'b\"");
+    }
+}
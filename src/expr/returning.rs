@@ -1,6 +1,66 @@
-use crate::WriteSql;
+use alloc::string::String;
 
-use super::separator_optional;
+use crate::{Dialect, DialectError, WriteSql};
+
+use super::{separator_optional, ColumnExprList};
+
+/// Command returning the written rows
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ReturningCommand {
+    Insert,
+    Update,
+    Delete,
+}
+
+/// Version of a written row, available in Postgres 18 as the `OLD` and `NEW`
+/// qualifiers of a `RETURNING` clause.
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ReturningRow {
+    /// Row before the command, null for inserted rows
+    Old,
+    /// Row after the command, null for deleted rows
+    New,
+}
+
+impl ReturningRow {
+    pub const fn as_str(&self) -> &'static str {
+        match *self {
+            Self::Old => "OLD",
+            Self::New => "NEW",
+        }
+    }
+
+    /// Qualifies a column with the row version: `OLD.<column>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use sqlstr::expr::{column_list, ReturningRow};
+    /// let columns = column_list()
+    ///     .column(ReturningRow::Old.qualify("price"))
+    ///     .column(ReturningRow::New.qualify("price"));
+    ///
+    /// assert_eq!(columns.expr().as_str(), "OLD.price, NEW.price");
+    /// ```
+    pub fn qualify(&self, column: &str) -> String {
+        let prefix = self.as_str();
+        let mut qualified = String::with_capacity(prefix.len() + column.len() + 1);
+        qualified.push_str(prefix);
+        qualified.push('.');
+        qualified.push_str(column);
+        qualified
+    }
+
+    /// Pseudo table of the row version in a MSSQL `OUTPUT` clause.
+    const fn output_table(&self) -> &'static str {
+        match *self {
+            Self::Old => "DELETED",
+            Self::New => "INSERTED",
+        }
+    }
+}
 
 /// Write a `RETURNING` clause to compute the values that will be returned from
 /// the query.
@@ -25,3 +85,339 @@ where
     separator_optional(sql);
     sql.push_cmd("RETURNING");
 }
+
+/// Write a `RETURNING *` clause to return the whole written rows.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, expr::{delete_from, returning_all}};
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// delete_from(&mut sql, "session");
+/// returning_all(&mut sql);
+///
+/// assert_eq!(sql.as_command(), "DELETE FROM session RETURNING *");
+/// ```
+pub fn returning_all<Sql, Arg>(sql: &mut Sql)
+where
+    Sql: WriteSql<Arg>,
+{
+    separator_optional(sql);
+    sql.push_cmd("RETURNING *");
+}
+
+/// Write a `RETURNING <columns>` clause with a list of columns, or
+/// `RETURNING *` when the list is empty.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, expr::{column_list, returning_columns}};
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// returning_columns(&mut sql, &column_list().column("id").column_as("created_at", "created"));
+///
+/// assert_eq!(sql.as_command(), "RETURNING id, created_at AS created");
+/// ```
+pub fn returning_columns<Sql, Arg>(sql: &mut Sql, columns: &ColumnExprList<'_>)
+where
+    Sql: WriteSql<Arg>,
+{
+    let Some((first, rest)) = columns.split_first() else {
+        return returning_all(sql);
+    };
+
+    returning(sql);
+    sql.push_cmd(" ");
+    sql.push_cmd(first);
+    for column in rest {
+        sql.push_cmd(", ");
+        sql.push_cmd(column);
+    }
+}
+
+/// Splits the `OLD.` or `NEW.` qualifier of a column.
+fn row_qualifier(column: &str) -> (Option<ReturningRow>, &str) {
+    for row in [ReturningRow::Old, ReturningRow::New] {
+        let prefix = row.as_str();
+        let qualified = column.len() > prefix.len()
+            && column.as_bytes()[prefix.len()] == b'.'
+            && column[..prefix.len()].eq_ignore_ascii_case(prefix);
+        if qualified {
+            return (Some(row), &column[prefix.len() + 1..]);
+        }
+    }
+    (None, column)
+}
+
+/// Pseudo table row and column name of a column in an `OUTPUT` clause.
+fn output_column(
+    command: ReturningCommand,
+    column: &str,
+) -> Result<(ReturningRow, &str), DialectError> {
+    let (row, column) = row_qualifier(column);
+    let row = match (command, row) {
+        (ReturningCommand::Insert, Some(ReturningRow::Old)) => {
+            return Err(DialectError::Unsupported("OLD row in INSERT OUTPUT"))
+        }
+        (ReturningCommand::Delete, Some(ReturningRow::New)) => {
+            return Err(DialectError::Unsupported("NEW row in DELETE OUTPUT"))
+        }
+        (_, Some(row)) => row,
+        (ReturningCommand::Delete, None) => ReturningRow::Old,
+        (ReturningCommand::Insert | ReturningCommand::Update, None) => ReturningRow::New,
+    };
+    Ok((row, column))
+}
+
+fn push_output_column<Sql, Arg>(sql: &mut Sql, (row, column): (ReturningRow, &str))
+where
+    Sql: WriteSql<Arg>,
+{
+    sql.push_cmd(row.output_table());
+    sql.push_cmd(".");
+    sql.push_cmd(column);
+}
+
+/// Writes the returned rows for dialects that return them in the middle of the
+/// command.
+///
+/// For [Dialect::MsSql] an `OUTPUT` clause is written, with the columns
+/// qualified by `INSERTED` or `DELETED` as the command requires, `OLD.` and
+/// `NEW.` qualifiers are translated to `DELETED.` and `INSERTED.`. The clause
+/// must be written after the column list of an `INSERT`, after the `SET`
+/// assignments of an `UPDATE` and after the table of a `DELETE`.
+///
+/// Nothing is written for the other dialects, the rows are returned with
+/// [returning_rows] at the end of the command. Both writers return an error
+/// for [Dialect::MySql], which can not return the written rows.
+///
+/// Columns must be plain or aliased column names to be qualified.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect, DialectError, sqlvalue};
+/// # use sqlstr::expr::{
+/// #     column_list, update_table, set_update, filter_where, output_rows, returning_rows,
+/// #     ReturningCommand, ReturningRow
+/// # };
+/// # fn main() -> Result<(), DialectError> {
+/// let columns = column_list().column("id").column(ReturningRow::Old.qualify("price"));
+///
+/// for (dialect, expected) in [
+///     (
+///         Dialect::Postgres,
+///         "UPDATE product SET price = price * 2 WHERE active RETURNING id, OLD.price",
+///     ),
+///     (
+///         Dialect::MsSql,
+///         "UPDATE product SET price = price * 2 OUTPUT INSERTED.id, DELETED.price WHERE active",
+///     ),
+/// ] {
+///     let mut sql: SqlCommand<Void> = SqlCommand::default();
+///     update_table(&mut sql, "product");
+///     set_update(&mut sql);
+///     sql.push_cmd(" price = price * 2");
+///     output_rows(&mut sql, dialect, ReturningCommand::Update, &columns)?;
+///     filter_where(&mut sql);
+///     sql.push_cmd(" active");
+///     returning_rows(&mut sql, dialect, &columns)?;
+///
+///     assert_eq!(sql.as_command(), expected);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn output_rows<Sql, Arg>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    command: ReturningCommand,
+    columns: &ColumnExprList<'_>,
+) -> Result<(), DialectError>
+where
+    Sql: WriteSql<Arg>,
+{
+    match dialect {
+        Dialect::Postgres | Dialect::Sqlite => Ok(()),
+        Dialect::MySql => Err(DialectError::Unsupported("RETURNING")),
+        Dialect::MsSql => {
+            for column in columns.iter() {
+                output_column(command, column)?;
+            }
+
+            separator_optional(sql);
+            sql.push_cmd("OUTPUT ");
+
+            let Some((first, rest)) = columns.split_first() else {
+                let row = match command {
+                    ReturningCommand::Delete => ReturningRow::Old,
+                    ReturningCommand::Insert | ReturningCommand::Update => ReturningRow::New,
+                };
+                sql.push_cmd(row.output_table());
+                sql.push_cmd(".*");
+                return Ok(());
+            };
+
+            // every column was checked before writing the clause
+            push_output_column(sql, output_column(command, first)?);
+            for column in rest {
+                sql.push_cmd(", ");
+                push_output_column(sql, output_column(command, column)?);
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Writes the returned rows for dialects that return them at the end of the
+/// command.
+///
+/// `RETURNING <columns>` is written for Postgres and SQLite, nothing is
+/// written for [Dialect::MsSql], where the rows are returned with
+/// [output_rows], and an error is returned for [Dialect::MySql].
+///
+/// `OLD.` and `NEW.` qualifiers are only accepted by Postgres, an error is
+/// returned, without writing anything, for qualified columns in SQLite.
+pub fn returning_rows<Sql, Arg>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    columns: &ColumnExprList<'_>,
+) -> Result<(), DialectError>
+where
+    Sql: WriteSql<Arg>,
+{
+    match dialect {
+        Dialect::Postgres => {
+            returning_columns(sql, columns);
+            Ok(())
+        }
+        Dialect::Sqlite => {
+            if columns
+                .iter()
+                .any(|column| row_qualifier(column).0.is_some())
+            {
+                return Err(DialectError::Unsupported("OLD/NEW in RETURNING"));
+            }
+            returning_columns(sql, columns);
+            Ok(())
+        }
+        Dialect::MySql => Err(DialectError::Unsupported("RETURNING")),
+        Dialect::MsSql => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        expr::{column_list, delete_from, insert_into, values, Group},
+        test::TestArgs,
+        SqlCommand,
+    };
+
+    fn insert_returning(dialect: Dialect) -> Result<SqlCommand<TestArgs>, DialectError> {
+        let columns = column_list();
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        insert_into(&mut sql, "user");
+        sql.push_cmd(" (name)");
+        output_rows(&mut sql, dialect, ReturningCommand::Insert, &columns)?;
+        values(&mut sql);
+        let mut group = Group::open(&mut sql);
+        group.push_value("foo").unwrap();
+        group.close();
+        returning_rows(&mut sql, dialect, &columns)?;
+        Ok(sql)
+    }
+
+    #[test]
+    fn insert_output_position() {
+        let sql = insert_returning(Dialect::MsSql).unwrap();
+        assert_eq!(
+            sql.as_command(),
+            "INSERT INTO user (name) OUTPUT INSERTED.* VALUES ($1)"
+        );
+
+        let sql = insert_returning(Dialect::Sqlite).unwrap();
+        assert_eq!(
+            sql.as_command(),
+            "INSERT INTO user (name) VALUES ($1) RETURNING *"
+        );
+        assert_eq!(sql.arguments.as_str(), "foo;");
+
+        assert_eq!(
+            insert_returning(Dialect::MySql).err(),
+            Some(DialectError::Unsupported("RETURNING"))
+        );
+    }
+
+    #[test]
+    fn delete_output_qualifiers() {
+        let columns = column_list().column("id").column_as("old.name", "name");
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        delete_from(&mut sql, "user");
+        output_rows(&mut sql, Dialect::MsSql, ReturningCommand::Delete, &columns).unwrap();
+
+        assert_eq!(
+            sql.as_command(),
+            "DELETE FROM user OUTPUT DELETED.id, DELETED.name AS name"
+        );
+
+        let columns = column_list().column(ReturningRow::New.qualify("id"));
+        assert_eq!(
+            output_rows(&mut sql, Dialect::MsSql, ReturningCommand::Delete, &columns),
+            Err(DialectError::Unsupported("NEW row in DELETE OUTPUT"))
+        );
+    }
+
+    #[test]
+    fn output_error_writes_nothing() {
+        let columns = column_list()
+            .column("id")
+            .column(ReturningRow::Old.qualify("name"));
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        insert_into(&mut sql, "user");
+        sql.push_cmd(" (name)");
+
+        assert_eq!(
+            output_rows(&mut sql, Dialect::MsSql, ReturningCommand::Insert, &columns),
+            Err(DialectError::Unsupported("OLD row in INSERT OUTPUT"))
+        );
+        assert_eq!(sql.as_command(), "INSERT INTO user (name)");
+    }
+
+    #[test]
+    fn sqlite_returning_qualifiers() {
+        let columns = column_list().column("id").column_as("name", "user_name");
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        delete_from(&mut sql, "user");
+        returning_rows(&mut sql, Dialect::Sqlite, &columns).unwrap();
+        assert_eq!(
+            sql.as_command(),
+            "DELETE FROM user RETURNING id, name AS user_name"
+        );
+
+        let columns = column_list()
+            .column("id")
+            .column(ReturningRow::Old.qualify("name"));
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        delete_from(&mut sql, "user");
+        assert_eq!(
+            returning_rows(&mut sql, Dialect::Sqlite, &columns),
+            Err(DialectError::Unsupported("OLD/NEW in RETURNING"))
+        );
+        assert_eq!(sql.as_command(), "DELETE FROM user");
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        delete_from(&mut sql, "user");
+        returning_rows(&mut sql, Dialect::Postgres, &columns).unwrap();
+        assert_eq!(sql.as_command(), "DELETE FROM user RETURNING id, OLD.name");
+    }
+
+    #[test]
+    fn row_qualifiers() {
+        assert_eq!(row_qualifier("OLD.id"), (Some(ReturningRow::Old), "id"));
+        assert_eq!(row_qualifier("new.id"), (Some(ReturningRow::New), "id"));
+        assert_eq!(row_qualifier("older.id"), (None, "older.id"));
+        assert_eq!(row_qualifier("NEW"), (None, "NEW"));
+    }
+}
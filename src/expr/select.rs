@@ -1,5 +1,7 @@
+use core::marker::PhantomData;
+
 use super::separator_optional;
-use crate::WriteSql;
+use crate::{ArgumentBuffer, SqlExpr, WriteSql};
 
 /// SELECT
 ///
@@ -76,6 +78,149 @@ where
     sql.push_cmd("SELECT DISTINCT");
 }
 
+/// SELECT DISTINCT ON
+///
+/// The select query will return only the first row of each set of rows where
+/// the given expressions evaluate to equal.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, SqlExpr, sqlexpr, expr::select_distinct_on};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// select_distinct_on(&mut sql, [sqlexpr::<u8>("location"), sqlexpr("date_trunc('day', time)")])?;
+///
+/// assert_eq!(sql.as_command(), "SELECT DISTINCT ON (location, date_trunc('day', time))");
+/// # Ok(())
+/// # }
+/// ```
+pub fn select_distinct_on<'ex, Sql, Arg, I, T>(sql: &mut Sql, exprs: I) -> Result<(), Arg::Error>
+where
+    Sql: WriteSql<Arg>,
+    Arg: ArgumentBuffer<T>,
+    I: IntoIterator<Item = SqlExpr<'ex, T>>,
+{
+    separator_optional(sql);
+    sql.push_cmd("SELECT DISTINCT ON (");
+
+    let mut exprs = exprs.into_iter();
+    if let Some(expr) = exprs.next() {
+        sql.push_expr(expr)?;
+    }
+    for expr in exprs {
+        sql.push_cmd(", ");
+        sql.push_expr(expr)?;
+    }
+
+    sql.push_cmd(")");
+    Ok(())
+}
+
+/// Writer of a list of select items, that may be columns or expressions with
+/// arguments.
+///
+/// Each item is written when it is added, separated from the previous one by
+/// a comma, so each expression can bind arguments of its own type.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, SqlExpr, sqlexpr, sqlvalue};
+/// # use sqlstr::expr::{select, from_table, SelectList};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+///
+/// select(&mut sql);
+/// let mut items = SelectList::open(&mut sql);
+/// items.column("id");
+/// items.column_as("full_name", "name");
+/// items.expr_as(
+///     [sqlexpr("ST_Distance(geom, "), sqlvalue("POINT(0 0)"), sqlexpr(")")],
+///     "dist",
+/// )?;
+/// items.expr([sqlexpr("rank > "), sqlvalue(10)])?;
+/// from_table(&mut sql, "place");
+///
+/// assert_eq!(
+///     sql.as_command(),
+///     "SELECT id, full_name AS name, ST_Distance(geom, $1) AS dist, rank > $2 FROM place"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub struct SelectList<'cmd, Sql: WriteSql<Arg>, Arg> {
+    sql: &'cmd mut Sql,
+    empty: bool,
+    _arg: PhantomData<Arg>,
+}
+
+impl<'cmd, Sql, Arg> SelectList<'cmd, Sql, Arg>
+where
+    Sql: WriteSql<Arg>,
+{
+    pub fn open(sql: &'cmd mut Sql) -> Self {
+        separator_optional(sql);
+        Self {
+            sql,
+            empty: true,
+            _arg: PhantomData,
+        }
+    }
+
+    fn item(&mut self) {
+        if !self.empty {
+            self.sql.push_cmd(", ");
+        }
+        self.empty = false;
+    }
+
+    /// Writes a `<column>` item.
+    pub fn column(&mut self, column: &str) {
+        self.item();
+        self.sql.push_cmd(column);
+    }
+
+    /// Writes a `<column> AS <alias>` item.
+    pub fn column_as(&mut self, column: &str, alias: &str) {
+        self.column(column);
+        self.sql.push_cmd(" AS ");
+        self.sql.push_cmd(alias);
+    }
+
+    /// Writes an expression item from a sequence of sql fragments.
+    pub fn expr<'ex, I, T>(&mut self, expr: I) -> Result<(), Arg::Error>
+    where
+        Arg: ArgumentBuffer<T>,
+        I: IntoIterator<Item = SqlExpr<'ex, T>>,
+    {
+        self.item();
+        for fragment in expr {
+            self.sql.push_expr(fragment)?;
+        }
+        Ok(())
+    }
+
+    /// Writes an `<expr> AS <alias>` item from a sequence of sql fragments.
+    pub fn expr_as<'ex, I, T>(&mut self, expr: I, alias: &str) -> Result<(), Arg::Error>
+    where
+        Arg: ArgumentBuffer<T>,
+        I: IntoIterator<Item = SqlExpr<'ex, T>>,
+    {
+        self.expr(expr)?;
+        self.sql.push_cmd(" AS ");
+        self.sql.push_cmd(alias);
+        Ok(())
+    }
+
+    /// Returns `true` if no item was written.
+    pub fn is_empty(&self) -> bool {
+        self.empty
+    }
+}
+
 #[macro_export]
 macro_rules! static_select {
     (SELECT) => {
@@ -87,12 +232,40 @@ macro_rules! static_select {
     (SELECT DISTINCT) => {
         "SELECT DISTINCT"
     };
+    (SELECT DISTINCT ON ($first:literal $(, $expr:literal)* $(,)?)) => {
+        concat!("SELECT DISTINCT ON (", $first, $(", ", $expr,)* ")")
+    };
 }
 
 pub use static_select;
 
 #[cfg(test)]
 mod test {
+    use super::*;
+    use crate::{expr::from_table, sqlexpr, sqlvalue, test::TestArgs, SqlCommand};
+
+    #[test]
+    fn distinct_on_select_list() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+
+        select_distinct_on(&mut sql, [sqlexpr("user_id"), sqlvalue("x")]).unwrap();
+        let mut items = SelectList::open(&mut sql);
+        items.column("user_id");
+        items.expr([sqlexpr("score * "), sqlvalue(2)]).unwrap();
+        items
+            .expr_as([sqlexpr("name = "), sqlvalue("foo")], "named")
+            .unwrap();
+        items.column_as("created", "since");
+        assert!(!items.is_empty());
+        from_table(&mut sql, "score");
+
+        assert_eq!(
+            sql.as_command(),
+            "SELECT DISTINCT ON (user_id, $1) user_id, score * $2, name = $3 AS named, created AS since FROM score"
+        );
+        assert_eq!(sql.arguments.as_str(), "x;2;foo;");
+    }
+
     #[test]
     fn static_select_macro() {
         assert_eq!(static_select!(SELECT), "SELECT");
@@ -100,5 +273,10 @@ mod test {
         assert_eq!(static_select!(SELECT ALL), "SELECT ALL");
 
         assert_eq!(static_select!(SELECT DISTINCT), "SELECT DISTINCT");
+
+        assert_eq!(
+            static_select!(SELECT DISTINCT ON ("id", "created")),
+            "SELECT DISTINCT ON (id, created)"
+        );
     }
 }
@@ -1,5 +1,7 @@
-use super::{item_separator_optional, separator_optional};
-use crate::{ArgumentBuffer, WriteSql};
+use core::marker::PhantomData;
+
+use super::{cast, item_separator_optional, separator_optional};
+use crate::{sqlvalue, ArgumentBuffer, WriteSql};
 
 /// Write a `VALUES` clause to start a list of expressions to be used in the
 /// `INSERT` command.
@@ -65,6 +67,209 @@ where
     Ok(())
 }
 
+/// Writer of the values of a row in [values_rows].
+///
+/// The values are separated by commas, the values of the first row are cast
+/// to the types given to [values_rows].
+pub struct ValuesRow<'cmd, 't, Sql, Arg> {
+    sql: &'cmd mut Sql,
+    types: &'t [&'t str],
+    count: usize,
+    _arg: PhantomData<Arg>,
+}
+
+impl<'cmd, 't, Sql, Arg> ValuesRow<'cmd, 't, Sql, Arg>
+where
+    Sql: WriteSql<Arg>,
+{
+    /// Write the next value of the row.
+    pub fn value<T>(&mut self, value: T) -> Result<(), Arg::Error>
+    where
+        Arg: ArgumentBuffer<T>,
+    {
+        if self.count > 0 {
+            self.sql.push_cmd(", ");
+        }
+        match self.types.get(self.count) {
+            Some(typ) => cast(self.sql, sqlvalue(value), typ)?,
+            None => self.sql.push_value(value)?,
+        }
+        self.count += 1;
+        Ok(())
+    }
+}
+
+/// Write the rows of a `VALUES` list: `VALUES ($1, $2), ($3, $4)`.
+///
+/// Each row is written by `write_row` with a [ValuesRow], so the values of a
+/// row can have different types. The placeholders of the first row are
+/// wrapped in a `CAST` with the types in order, so the types of the columns
+/// can be inferred from them, the remaining placeholders of the row are not
+/// cast.
+///
+/// Returns `false` and writes nothing when there are no rows, a `VALUES`
+/// list must have at least one row.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void};
+/// # use sqlstr::expr::{values_rows, join_subquery, JoinType, JoinLateral};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// join_subquery(
+///     &mut sql,
+///     JoinType::Inner,
+///     JoinLateral::None,
+///     |sql| {
+///         let rows = [(1, "apple"), (2, "pear")];
+///         values_rows(sql, rows, &["int8"], |row, (id, name)| {
+///             row.value(id)?;
+///             row.value(name)
+///         })
+///         .map(|_| ())
+///     },
+///     "v",
+///     ["id", "name"],
+/// )?;
+///
+/// assert_eq!(
+///     sql.as_command(),
+///     "INNER JOIN (VALUES (CAST ($1 AS int8), $2), ($3, $4)) AS v (id, name)"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn values_rows<Sql, Arg, I, R, F, E>(
+    sql: &mut Sql,
+    rows: I,
+    types: &[&str],
+    write_row: F,
+) -> Result<bool, E>
+where
+    Sql: WriteSql<Arg>,
+    I: IntoIterator<Item = R>,
+    F: FnMut(&mut ValuesRow<'_, '_, Sql, Arg>, R) -> Result<(), E>,
+{
+    let mut rows = rows.into_iter().peekable();
+    if rows.peek().is_none() {
+        return Ok(false);
+    }
+
+    write_rows(sql, rows, types, write_row)?;
+    Ok(true)
+}
+
+fn write_rows<Sql, Arg, I, R, F, E>(
+    sql: &mut Sql,
+    rows: I,
+    types: &[&str],
+    mut write_row: F,
+) -> Result<(), E>
+where
+    Sql: WriteSql<Arg>,
+    I: Iterator<Item = R>,
+    F: FnMut(&mut ValuesRow<'_, '_, Sql, Arg>, R) -> Result<(), E>,
+{
+    values(sql);
+
+    for (i, row) in rows.enumerate() {
+        sql.push_cmd(if i == 0 { " (" } else { ", (" });
+
+        let mut writer = ValuesRow {
+            sql: &mut *sql,
+            types: if i == 0 { types } else { &[] },
+            count: 0,
+            _arg: PhantomData,
+        };
+        write_row(&mut writer, row)?;
+
+        sql.push_cmd(")");
+    }
+
+    Ok(())
+}
+
+/// Write a `VALUES` list as a table source:
+/// `(VALUES ($1, $2), ($3, $4)) AS <alias> (<column>, ...)`.
+///
+/// The table is written after the `FROM` keyword of a `SELECT` or a
+/// `UPDATE ... FROM`, see [values_rows] for the types and to write the rows.
+/// Returns `false` and writes nothing when there are no rows.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void};
+/// # use sqlstr::expr::{update_table, set_update, from_tables, filter_where, values_table};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// update_table(&mut sql, "stock");
+/// set_update(&mut sql);
+/// sql.push_cmd(" qty = v.qty");
+/// from_tables(&mut sql, []);
+/// values_table(
+///     &mut sql,
+///     [(1, 10), (2, 5)],
+///     "v",
+///     &["id", "qty"],
+///     &["int8", "int4"],
+///     |row, (id, qty)| {
+///         row.value(id)?;
+///         row.value(qty)
+///     },
+/// )?;
+/// filter_where(&mut sql);
+/// sql.push_cmd(" stock.id = v.id");
+///
+/// assert_eq!(
+///     sql.as_command(),
+///     "UPDATE stock SET qty = v.qty \
+///     FROM (VALUES (CAST ($1 AS int8), CAST ($2 AS int4)), ($3, $4)) AS v (id, qty) \
+///     WHERE stock.id = v.id"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn values_table<Sql, Arg, I, R, F, E>(
+    sql: &mut Sql,
+    rows: I,
+    alias: &str,
+    columns: &[&str],
+    types: &[&str],
+    write_row: F,
+) -> Result<bool, E>
+where
+    Sql: WriteSql<Arg>,
+    I: IntoIterator<Item = R>,
+    F: FnMut(&mut ValuesRow<'_, '_, Sql, Arg>, R) -> Result<(), E>,
+{
+    let mut rows = rows.into_iter().peekable();
+    if rows.peek().is_none() {
+        return Ok(false);
+    }
+
+    separator_optional(sql);
+    sql.push_cmd("(");
+    write_rows(sql, rows, types, write_row)?;
+    sql.push_cmd(") AS ");
+    sql.push_cmd(alias);
+
+    if let Some((first, rest)) = columns.split_first() {
+        sql.push_cmd(" (");
+        sql.push_cmd(first);
+        for column in rest {
+            sql.push_cmd(", ");
+            sql.push_cmd(column);
+        }
+        sql.push_cmd(")");
+    }
+
+    Ok(true)
+}
+
 #[macro_export]
 macro_rules! write_variadic {
     (ArgumentBufferError = $argbuf_error_type:ty; $sql:expr, $value1:expr) => {{
@@ -113,7 +318,7 @@ mod test {
 
     use super::*;
     use crate::{
-        expr::{select, separator},
+        expr::{from_tables, select, separator},
         test::{display_iter, TestArgs},
         SqlCommand,
     };
@@ -177,4 +382,67 @@ mod test {
         assert_eq!(sql.as_command(), "SELECT $1, $2, $3, $4, $5");
         assert_eq!(sql.arguments.as_str(), "str;false;string;10;[120,360,0];");
     }
+
+    #[test]
+    fn select_from_values_table() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+
+        select(&mut sql);
+        sql.push_cmd(" v.name");
+        from_tables(&mut sql, []);
+        let written = values_table(
+            &mut sql,
+            [("a", 1), ("b", 2)],
+            "v",
+            &["name", "rank"],
+            &[],
+            |row, (name, rank)| {
+                row.value(name)?;
+                row.value(rank)
+            },
+        )
+        .unwrap();
+
+        assert!(written);
+        assert_eq!(
+            sql.as_command(),
+            "SELECT v.name FROM (VALUES ($1, $2), ($3, $4)) AS v (name, rank)"
+        );
+        assert_eq!(sql.arguments.as_str(), "a;1;b;2;");
+    }
+
+    #[test]
+    fn values_rows_partial_casts() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+
+        values_rows(&mut sql, [[1, 2, 3]], &["int2"], |row, values| {
+            values.into_iter().try_for_each(|value| row.value(value))
+        })
+        .unwrap();
+
+        assert_eq!(sql.as_command(), "VALUES (CAST ($1 AS int2), $2, $3)");
+        assert_eq!(sql.arguments.as_str(), "1;2;3;");
+    }
+
+    #[test]
+    fn values_empty_rows() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        let rows: [(i32, &str); 0] = [];
+
+        let written = values_rows(&mut sql, rows, &["int4"], |row, (id, name)| {
+            row.value(id)?;
+            row.value(name)
+        })
+        .unwrap();
+        assert!(!written);
+
+        let written = values_table(&mut sql, rows, "v", &["id"], &[], |row, (id, _)| {
+            row.value(id)
+        })
+        .unwrap();
+        assert!(!written);
+
+        assert_eq!(sql.as_command(), "");
+        assert_eq!(sql.arguments.as_str(), "");
+    }
 }
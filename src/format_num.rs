@@ -55,6 +55,24 @@ pub fn format_u32_base10(mut number: u32, buf: &mut [u8; 10]) -> &str {
     unsafe { core::str::from_utf8_unchecked(&buf[index.wrapping_add(1)..]) }
 }
 
+/// Formats the u64 number into the `buf` and return a reference from the written buffer.
+pub fn format_u64_base10(mut number: u64, buf: &mut [u8; 20]) -> &str {
+    let mut index = buf.len();
+    loop {
+        index -= 1;
+        buf[index] = LOOKUP[(number % 10) as usize];
+        number /= 10;
+        if number == 0 {
+            break;
+        }
+    }
+
+    // SAFETY:
+    // The sliced `buf` contains a formatted positive number (`u64`) represented
+    // through (`b'0'..=b'9'`)
+    unsafe { core::str::from_utf8_unchecked(&buf[index..]) }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -88,4 +106,15 @@ mod test {
         assert_eq!(formated, "8293742");
         assert_eq!(buf, [0, 0, 0, b'8', b'2', b'9', b'3', b'7', b'4', b'2']);
     }
+
+    #[test]
+    fn u64_inputs() {
+        let mut buf = [0; 20];
+        assert_eq!(format_u64_base10(0, &mut buf), "0");
+        assert_eq!(format_u64_base10(907, &mut buf), "907");
+        assert_eq!(
+            format_u64_base10(u64::MAX, &mut buf),
+            "18446744073709551615"
+        );
+    }
 }
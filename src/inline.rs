@@ -0,0 +1,460 @@
+//! Commands with the arguments inlined as sql literals, for logging and
+//! debugging.
+//!
+//! A [InlineArgs] buffer keeps the [SqlLiteral] of each argument pushed into
+//! the command, and [SqlCommand::to_inline_sql] writes the command with each
+//! `$N` placeholder replaced by the literal of its argument, so it can be
+//! pasted into a sql shell.
+//!
+//! The inlined command is not meant to be executed by the application, the
+//! arguments must still be bound to the placeholders.
+//!
+//! Inlining is opt-in, the literals are kept while the command is built:
+//!
+//! - only a command built with an [InlineArgs] buffer, wrapping the buffer of
+//!   the database driver, can be inlined, a command built with the driver's
+//!   buffer alone has no literals to write.
+//! - every pushed value must implement [SqlLiteral], which is implemented for
+//!   the integers but `u8`, floats, booleans, strings, bytes, options, slices
+//!   and [Value]. Values of other types, like uuids or dates, can not be
+//!   pushed into the buffer, they can be pushed as a string or a [Value].
+//! - each push formats the literal of the value into a new `String`, even if
+//!   the command is never inlined. Wrap the buffer only when the command is
+//!   logged.
+
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{self, Display, Write};
+
+use crate::{ast::Value, placeholder::Placeholders, ArgumentBuffer, Dialect, SqlCommand, Void};
+
+/// Value written as a sql literal of a dialect.
+///
+/// `u8` is not a literal, a `[u8]` is written as a binary string.
+pub trait SqlLiteral {
+    /// Writes the value as a sql literal of the dialect.
+    fn fmt_literal(&self, dialect: Dialect, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// Sql literal of the value, written with [Display].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use sqlstr::Dialect;
+    /// # use sqlstr::inline::SqlLiteral;
+    /// assert_eq!("it's".literal(Dialect::Postgres).to_string(), "'it''s'");
+    /// assert_eq!(true.literal(Dialect::MsSql).to_string(), "1");
+    /// assert_eq!(b"\x01\xff".literal(Dialect::MySql).to_string(), "X'01ff'");
+    /// assert_eq!([1, 2].literal(Dialect::Postgres).to_string(), "ARRAY[1, 2]");
+    /// assert_eq!(None::<i32>.literal(Dialect::Sqlite).to_string(), "NULL");
+    /// ```
+    fn literal(&self, dialect: Dialect) -> Literal<'_, Self> {
+        Literal {
+            value: self,
+            dialect,
+        }
+    }
+}
+
+/// Sql literal of a value for a dialect, see [SqlLiteral::literal]
+#[derive(Clone, Copy)]
+pub struct Literal<'v, T: ?Sized> {
+    value: &'v T,
+    dialect: Dialect,
+}
+
+impl<'v, T> Display for Literal<'v, T>
+where
+    T: SqlLiteral + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt_literal(self.dialect, f)
+    }
+}
+
+macro_rules! literal_integer {
+    ($($ty:ty),+) => {
+        $(
+            impl SqlLiteral for $ty {
+                fn fmt_literal(&self, _: Dialect, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    Display::fmt(self, f)
+                }
+            }
+        )+
+    };
+}
+
+literal_integer!(i8, i16, i32, i64, i128, isize, u16, u32, u64, u128, usize);
+
+macro_rules! literal_float {
+    ($($ty:ty),+) => {
+        $(
+            /// The special values are written as the quoted values of Postgres:
+            /// `'NaN'`, `'Infinity'` and `'-Infinity'`.
+            impl SqlLiteral for $ty {
+                fn fmt_literal(&self, _: Dialect, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    if self.is_nan() {
+                        f.write_str("'NaN'")
+                    } else if self.is_infinite() && self.is_sign_positive() {
+                        f.write_str("'Infinity'")
+                    } else if self.is_infinite() {
+                        f.write_str("'-Infinity'")
+                    } else {
+                        Display::fmt(self, f)
+                    }
+                }
+            }
+        )+
+    };
+}
+
+literal_float!(f32, f64);
+
+/// `TRUE` and `FALSE` in Postgres and MySQL, `1` and `0` in SQLite and MSSQL
+impl SqlLiteral for bool {
+    fn fmt_literal(&self, dialect: Dialect, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match (dialect, *self) {
+            (Dialect::Postgres | Dialect::MySql, true) => "TRUE",
+            (Dialect::Postgres | Dialect::MySql, false) => "FALSE",
+            (Dialect::Sqlite | Dialect::MsSql, true) => "1",
+            (Dialect::Sqlite | Dialect::MsSql, false) => "0",
+        })
+    }
+}
+
+/// Quoted string, the quotes are doubled and in MySQL the backslashes are
+/// escaped.
+impl SqlLiteral for str {
+    fn fmt_literal(&self, dialect: Dialect, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('\'')?;
+        for c in self.chars() {
+            match c {
+                '\'' => f.write_str("''")?,
+                '\\' if dialect == Dialect::MySql => f.write_str("\\\\")?,
+                c => f.write_char(c)?,
+            }
+        }
+        f.write_char('\'')
+    }
+}
+
+impl SqlLiteral for String {
+    fn fmt_literal(&self, dialect: Dialect, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt_literal(dialect, f)
+    }
+}
+
+impl<'s> SqlLiteral for Cow<'s, str> {
+    fn fmt_literal(&self, dialect: Dialect, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_ref().fmt_literal(dialect, f)
+    }
+}
+
+/// Binary string in hexadecimal: `'\x01ff'` in Postgres, `X'01ff'` in MySQL
+/// and SQLite, `0x01ff` in MSSQL
+impl SqlLiteral for [u8] {
+    fn fmt_literal(&self, dialect: Dialect, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (start, end) = match dialect {
+            Dialect::Postgres => ("'\\x", "'"),
+            Dialect::MySql | Dialect::Sqlite => ("X'", "'"),
+            Dialect::MsSql => ("0x", ""),
+        };
+        f.write_str(start)?;
+        for byte in self {
+            write!(f, "{byte:02x}")?;
+        }
+        f.write_str(end)
+    }
+}
+
+/// Array of literals: `ARRAY[a, b]` in Postgres, a list `(a, b)` in the
+/// other dialects
+impl<T> SqlLiteral for [T]
+where
+    T: SqlLiteral,
+{
+    fn fmt_literal(&self, dialect: Dialect, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (start, end) = match dialect {
+            // the type of a empty array can not be inferred from `ARRAY[]`
+            Dialect::Postgres if self.is_empty() => return f.write_str("'{}'"),
+            Dialect::Postgres => ("ARRAY[", "]"),
+            Dialect::MySql | Dialect::Sqlite | Dialect::MsSql => ("(", ")"),
+        };
+        f.write_str(start)?;
+        for (i, item) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            item.fmt_literal(dialect, f)?;
+        }
+        f.write_str(end)
+    }
+}
+
+impl<T, const N: usize> SqlLiteral for [T; N]
+where
+    [T]: SqlLiteral,
+{
+    fn fmt_literal(&self, dialect: Dialect, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt_literal(dialect, f)
+    }
+}
+
+impl<T> SqlLiteral for Vec<T>
+where
+    [T]: SqlLiteral,
+{
+    fn fmt_literal(&self, dialect: Dialect, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt_literal(dialect, f)
+    }
+}
+
+/// `NULL` for `None`
+impl<T> SqlLiteral for Option<T>
+where
+    T: SqlLiteral,
+{
+    fn fmt_literal(&self, dialect: Dialect, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Some(value) => value.fmt_literal(dialect, f),
+            None => f.write_str("NULL"),
+        }
+    }
+}
+
+impl<T> SqlLiteral for &T
+where
+    T: SqlLiteral + ?Sized,
+{
+    fn fmt_literal(&self, dialect: Dialect, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt_literal(dialect, f)
+    }
+}
+
+impl SqlLiteral for Value {
+    fn fmt_literal(&self, dialect: Dialect, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Null => f.write_str("NULL"),
+            Self::Bool(value) => value.fmt_literal(dialect, f),
+            Self::Int(value) => value.fmt_literal(dialect, f),
+            Self::Float(value) => value.fmt_literal(dialect, f),
+            Self::Text(value) => value.fmt_literal(dialect, f),
+            Self::Bytes(value) => value.fmt_literal(dialect, f),
+        }
+    }
+}
+
+/// Argument buffer keeping the sql literal of each argument, for
+/// [SqlCommand::to_inline_sql].
+///
+/// The arguments are also pushed into the wrapped buffer, which is [Void]
+/// by default, so the same command can be executed and logged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InlineArgs<Arg = Void> {
+    pub arguments: Arg,
+    dialect: Dialect,
+    /// Count of the wrapped buffer before the first literal
+    offset: u32,
+    literals: Vec<String>,
+}
+
+impl InlineArgs<Void> {
+    pub const fn new(dialect: Dialect) -> Self {
+        Self::with_arguments(dialect, Void::new())
+    }
+}
+
+impl<Arg> InlineArgs<Arg> {
+    /// Wraps the argument buffer `arguments`.
+    ///
+    /// The count of `arguments` is 0, the argument buffers of a
+    /// [SqlCommand] are written from the start.
+    pub const fn with_arguments(dialect: Dialect, arguments: Arg) -> Self {
+        Self {
+            arguments,
+            dialect,
+            offset: 0,
+            literals: Vec::new(),
+        }
+    }
+
+    pub const fn dialect(&self) -> Dialect {
+        self.dialect
+    }
+
+    /// Literals of the arguments, in the order they were pushed
+    pub fn literals(&self) -> &[String] {
+        &self.literals
+    }
+
+    pub fn into_arguments(self) -> Arg {
+        self.arguments
+    }
+}
+
+impl<Arg> Default for InlineArgs<Arg>
+where
+    Arg: Default,
+{
+    fn default() -> Self {
+        Self::with_arguments(Dialect::default(), Arg::default())
+    }
+}
+
+impl<T, Arg> ArgumentBuffer<T> for InlineArgs<Arg>
+where
+    T: SqlLiteral,
+    Arg: ArgumentBuffer<T>,
+{
+    type Error = Arg::Error;
+
+    fn push(&mut self, value: T) -> Result<(), Self::Error> {
+        if self.literals.is_empty() {
+            self.offset = self.arguments.count();
+        }
+        let literal = value.literal(self.dialect).to_string();
+        self.arguments.push(value)?;
+        self.literals.push(literal);
+        Ok(())
+    }
+
+    fn count(&self) -> u32 {
+        self.arguments.count()
+    }
+}
+
+impl<Arg> SqlCommand<InlineArgs<Arg>> {
+    /// Writes the command with each `$N` placeholder replaced by the sql
+    /// literal of its argument.
+    ///
+    /// The placeholders inside of quotes, comments and Postgres dollar-quoted
+    /// strings and the placeholders without an argument are not replaced.
+    /// Backslash escapes in quotes are read as the dialect of the buffer does,
+    /// in every quote for MySQL and only in `E'...'` strings for Postgres.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use sqlstr::{SqlCommand, Dialect};
+    /// # use sqlstr::expr::{select, from_table, filter_where};
+    /// # use sqlstr::inline::InlineArgs;
+    /// # use core::convert::Infallible;
+    /// # fn main() -> Result<(), Infallible> {
+    /// let mut sql = SqlCommand::new(String::new(), InlineArgs::new(Dialect::Postgres));
+    /// select(&mut sql);
+    /// sql.push_cmd(" id");
+    /// from_table(&mut sql, "user");
+    /// filter_where(&mut sql);
+    /// sql.push_cmd(" name = ");
+    /// sql.push_value("O'Brien")?;
+    /// sql.push_cmd(" AND active = ");
+    /// sql.push_value(true)?;
+    /// sql.push_cmd(" AND role = ANY(");
+    /// sql.push_value(["admin", "staff"])?;
+    /// sql.push_cmd(")");
+    ///
+    /// assert_eq!(
+    ///     sql.as_command(),
+    ///     "SELECT id FROM user WHERE name = $1 AND active = $2 AND role = ANY($3)"
+    /// );
+    /// assert_eq!(
+    ///     sql.to_inline_sql(),
+    ///     "SELECT id FROM user WHERE name = 'O''Brien' AND active = TRUE \
+    ///      AND role = ANY(ARRAY['admin', 'staff'])"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_inline_sql(&self) -> String {
+        let literals = &self.arguments.literals;
+        let offset = self.arguments.offset;
+
+        let mut sql = String::with_capacity(self.command.len());
+        let mut written = 0;
+        for (range, n) in Placeholders::new(&self.command, self.arguments.dialect) {
+            let literal = n
+                .checked_sub(offset + 1)
+                .and_then(|index| literals.get(index as usize));
+            if let Some(literal) = literal {
+                sql.push_str(&self.command[written..range.start]);
+                sql.push_str(literal);
+                written = range.end;
+            }
+        }
+        sql.push_str(&self.command[written..]);
+        sql
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::TestArgs;
+    use alloc::vec;
+
+    fn literal<T: SqlLiteral + ?Sized>(value: &T, dialect: Dialect) -> String {
+        value.literal(dialect).to_string()
+    }
+
+    #[test]
+    fn dialect_literals() {
+        assert_eq!(literal("a\\b'", Dialect::Postgres), "'a\\b'''");
+        assert_eq!(literal("a\\b'", Dialect::MySql), "'a\\\\b'''");
+        assert_eq!(literal(&false, Dialect::MySql), "FALSE");
+        assert_eq!(literal(&true, Dialect::Sqlite), "1");
+        assert_eq!(literal(&-1.5_f64, Dialect::MsSql), "-1.5");
+        assert_eq!(
+            literal(&f64::NEG_INFINITY, Dialect::Postgres),
+            "'-Infinity'"
+        );
+        assert_eq!(literal(&f32::NAN, Dialect::Postgres), "'NaN'");
+        assert_eq!(literal(&[0xde_u8, 0xad], Dialect::Postgres), "'\\xdead'");
+        assert_eq!(literal(&vec![0xde_u8, 0xad], Dialect::MsSql), "0xdead");
+        assert_eq!(literal(&Vec::<i64>::new(), Dialect::Postgres), "'{}'");
+        assert_eq!(literal(&[Some("a"), None], Dialect::Sqlite), "('a', NULL)");
+        assert_eq!(literal(&Value::Bytes(vec![1]), Dialect::Sqlite), "X'01'");
+    }
+
+    #[test]
+    fn inline_wrapped_arguments() {
+        let args = InlineArgs::with_arguments(Dialect::MySql, TestArgs::new());
+        let mut sql = SqlCommand::new(String::new(), args);
+        sql.push_cmd("SELECT '$1', ");
+        sql.push_value(10).unwrap();
+        sql.push_cmd(", $9, ");
+        sql.push_value("x").unwrap();
+
+        assert_eq!(sql.as_command(), "SELECT '$1', $1, $9, $2");
+        assert_eq!(sql.to_inline_sql(), "SELECT '$1', 10, $9, 'x'");
+        assert_eq!(sql.arguments.literals(), ["10", "'x'"]);
+        assert_eq!(sql.arguments.into_arguments().as_str(), "10;x;");
+    }
+
+    #[test]
+    fn inline_counted_arguments() {
+        let args = InlineArgs::with_arguments(Dialect::Postgres, Void::with_count(2));
+        let mut sql = SqlCommand::new(String::from("SELECT $1, $2, "), args);
+        sql.push_value(Some(3)).unwrap();
+
+        assert_eq!(sql.to_inline_sql(), "SELECT $1, $2, 3");
+    }
+
+    #[test]
+    fn inline_skipped_placeholders() {
+        let mut sql = SqlCommand::new(String::new(), InlineArgs::new(Dialect::MySql));
+        sql.push_cmd("SELECT 'it\\'s $1', ");
+        sql.push_value("a\\b").unwrap();
+        sql.push_cmd(" -- $1");
+
+        assert_eq!(sql.to_inline_sql(), "SELECT 'it\\'s $1', 'a\\\\b' -- $1");
+
+        let mut sql = SqlCommand::new(String::new(), InlineArgs::new(Dialect::Postgres));
+        sql.push_cmd("SELECT $tag$ $1 $tag$ /* $1 */, ");
+        sql.push_value(1).unwrap();
+
+        assert_eq!(sql.to_inline_sql(), "SELECT $tag$ $1 $tag$ /* $1 */, 1");
+    }
+}
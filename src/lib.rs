@@ -2,13 +2,32 @@
 #![no_std]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 mod base;
+mod dialect;
 mod format_num;
+mod placeholder;
 
+pub mod ast;
+pub mod copy;
+pub mod ddl;
+pub mod explain;
 pub mod expr;
+#[cfg(any(feature = "fmt", test))]
+pub mod inline;
+pub mod merge;
+pub mod notify;
+pub mod prepare;
+pub mod schema;
+pub mod tx;
 
 pub use crate::base::*;
+pub use crate::dialect::*;
+
+#[cfg(feature = "derive")]
+pub use sqlstr_derive::sql;
 
 #[cfg(test)]
 mod test;
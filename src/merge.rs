@@ -0,0 +1,376 @@
+//! `MERGE` command.
+//!
+//! The command is written with [merge_into], [merge_using] and [merge_on],
+//! followed by a `WHEN` guard for each branch. Since the guards are also a
+//! [WriteSql], the conditions and actions are written with the existing
+//! writers, like [set_column](crate::expr::set_column) and
+//! [values](crate::expr::values).
+//!
+//! [Postgres MERGE](https://www.postgresql.org/docs/current/sql-merge.html)
+//!
+//! # Example
+//!
+//! ```
+//! # use sqlstr::{SqlCommand, Void, WriteSql};
+//! # use sqlstr::expr::{set_column, values, Group};
+//! # use sqlstr::merge::{merge_into, merge_using, merge_on, when_matched, when_not_matched};
+//! let mut sql: SqlCommand<Void> = SqlCommand::default();
+//! merge_into(&mut sql, "stock", Some("s"));
+//! merge_using(&mut sql, "delivery", Some("d"));
+//! merge_on(&mut sql);
+//! sql.push_cmd(" s.item_id = d.item_id");
+//!
+//! let mut when = when_matched(&mut sql);
+//! when.then_update();
+//! set_column(&mut when, "qty");
+//! when.push_cmd(" s.qty + d.qty");
+//! when.close();
+//!
+//! let mut when = when_not_matched(&mut sql);
+//! when.then_insert(["item_id", "qty"]);
+//! values(&mut when);
+//! Group::open(when.sql()).push_cmd("d.item_id, d.qty");
+//! when.close();
+//!
+//! assert_eq!(
+//!     sql.as_command(),
+//!     "MERGE INTO stock AS s USING delivery AS d ON s.item_id = d.item_id \
+//!     WHEN MATCHED THEN UPDATE SET qty = s.qty + d.qty \
+//!     WHEN NOT MATCHED THEN INSERT (item_id, qty) VALUES (d.item_id, d.qty)"
+//! );
+//! ```
+
+use core::marker::PhantomData;
+
+use crate::{expr::separator_optional, ArgumentBuffer, SqlExpr, WriteSql};
+
+/// Branch on rows of the target, matched or not matched by the source
+pub struct Matched;
+
+/// Branch on rows of the source not matched by the target
+pub struct NotMatched;
+
+/// `WHEN` branch guard of a `MERGE` command.
+///
+/// Writes the `WHEN` clause when opened. The actions available depend on the
+/// kind of the branch: `UPDATE` and `DELETE` for [Matched] rows, `INSERT` for
+/// [NotMatched] rows. When dropped without an action, writes
+/// `THEN DO NOTHING`.
+pub struct MergeWhen<'cmd, Sql: WriteSql<Arg>, Arg, Kind> {
+    sql: &'cmd mut Sql,
+    action: bool,
+    _marker: PhantomData<(Arg, Kind)>,
+}
+
+impl<'cmd, Sql, Arg, Kind> MergeWhen<'cmd, Sql, Arg, Kind>
+where
+    Sql: WriteSql<Arg>,
+{
+    fn open(sql: &'cmd mut Sql, clause: &str) -> Self {
+        separator_optional(sql);
+        sql.push_cmd(clause);
+        Self {
+            sql,
+            action: false,
+            _marker: PhantomData,
+        }
+    }
+
+    fn then(&mut self, action: &str) {
+        separator_optional(self.sql);
+        self.sql.push_cmd(action);
+        self.action = true;
+    }
+
+    /// Starts the additional condition of the branch: `AND <condition>`, the
+    /// condition must be written afterwards.
+    pub fn and(&mut self) {
+        separator_optional(self.sql);
+        self.sql.push_cmd("AND");
+    }
+
+    /// Writes a `THEN DO NOTHING` action.
+    pub fn then_do_nothing(&mut self) {
+        self.then("THEN DO NOTHING");
+    }
+
+    pub fn close(self) {
+        core::mem::drop(self)
+    }
+
+    pub fn sql(&mut self) -> &mut Sql {
+        self.sql
+    }
+}
+
+impl<'cmd, Sql, Arg> MergeWhen<'cmd, Sql, Arg, Matched>
+where
+    Sql: WriteSql<Arg>,
+{
+    /// Writes a `THEN UPDATE SET` action, the assignments must be written
+    /// afterwards.
+    pub fn then_update(&mut self) {
+        self.then("THEN UPDATE SET");
+    }
+
+    /// Writes a `THEN DELETE` action.
+    pub fn then_delete(&mut self) {
+        self.then("THEN DELETE");
+    }
+}
+
+impl<'cmd, Sql, Arg> MergeWhen<'cmd, Sql, Arg, NotMatched>
+where
+    Sql: WriteSql<Arg>,
+{
+    /// Writes a `THEN INSERT (<columns>)` action, the `VALUES` clause must be
+    /// written afterwards.
+    pub fn then_insert<'c, I>(&mut self, columns: I)
+    where
+        I: IntoIterator<Item = &'c str>,
+    {
+        self.then("THEN INSERT");
+
+        let mut columns = columns.into_iter();
+        if let Some(column) = columns.next() {
+            self.sql.push_cmd(" (");
+            self.sql.push_cmd(column);
+            for column in columns {
+                self.sql.push_cmd(", ");
+                self.sql.push_cmd(column);
+            }
+            self.sql.push_cmd(")");
+        }
+    }
+
+    /// Writes a `THEN INSERT DEFAULT VALUES` action.
+    pub fn then_insert_default(&mut self) {
+        self.then("THEN INSERT DEFAULT VALUES");
+    }
+}
+
+impl<'cmd, Sql, Arg, Kind> WriteSql<Arg> for MergeWhen<'cmd, Sql, Arg, Kind>
+where
+    Sql: WriteSql<Arg>,
+{
+    fn push_expr<T>(&mut self, operand: SqlExpr<'_, T>) -> Result<(), <Arg>::Error>
+    where
+        Arg: ArgumentBuffer<T>,
+    {
+        self.sql.push_expr(operand)
+    }
+
+    fn push_value<T>(&mut self, value: T) -> Result<(), <Arg>::Error>
+    where
+        Arg: ArgumentBuffer<T>,
+    {
+        self.sql.push_value(value)
+    }
+
+    fn push_cmd(&mut self, expr: &str) {
+        self.sql.push_cmd(expr)
+    }
+
+    fn as_command(&self) -> &str {
+        self.sql.as_command()
+    }
+}
+
+impl<'cmd, Sql, Arg, Kind> Drop for MergeWhen<'cmd, Sql, Arg, Kind>
+where
+    Sql: WriteSql<Arg>,
+{
+    fn drop(&mut self) {
+        if !self.action {
+            self.then("THEN DO NOTHING");
+        }
+    }
+}
+
+/// Opens a `WHEN MATCHED` branch.
+pub fn when_matched<Sql, Arg>(sql: &mut Sql) -> MergeWhen<'_, Sql, Arg, Matched>
+where
+    Sql: WriteSql<Arg>,
+{
+    MergeWhen::open(sql, "WHEN MATCHED")
+}
+
+/// Opens a `WHEN NOT MATCHED BY SOURCE` branch, for rows of the target
+/// without a row in the source.
+pub fn when_not_matched_by_source<Sql, Arg>(sql: &mut Sql) -> MergeWhen<'_, Sql, Arg, Matched>
+where
+    Sql: WriteSql<Arg>,
+{
+    MergeWhen::open(sql, "WHEN NOT MATCHED BY SOURCE")
+}
+
+/// Opens a `WHEN NOT MATCHED` branch, for rows of the source without a row in
+/// the target.
+pub fn when_not_matched<Sql, Arg>(sql: &mut Sql) -> MergeWhen<'_, Sql, Arg, NotMatched>
+where
+    Sql: WriteSql<Arg>,
+{
+    MergeWhen::open(sql, "WHEN NOT MATCHED")
+}
+
+/// Writes a `MERGE INTO <target> [AS <alias>]` clause.
+pub fn merge_into<Sql, Arg>(sql: &mut Sql, target: &str, alias: Option<&str>)
+where
+    Sql: WriteSql<Arg>,
+{
+    separator_optional(sql);
+    sql.push_cmd("MERGE INTO ");
+    sql.push_cmd(target);
+    if let Some(alias) = alias {
+        sql.push_cmd(" AS ");
+        sql.push_cmd(alias);
+    }
+}
+
+/// Writes a `USING <source> [AS <alias>]` clause.
+///
+/// A subquery source can be written after [merge_using_query] inside a
+/// [Group](crate::expr::Group).
+pub fn merge_using<Sql, Arg>(sql: &mut Sql, source: &str, alias: Option<&str>)
+where
+    Sql: WriteSql<Arg>,
+{
+    separator_optional(sql);
+    sql.push_cmd("USING ");
+    sql.push_cmd(source);
+    if let Some(alias) = alias {
+        sql.push_cmd(" AS ");
+        sql.push_cmd(alias);
+    }
+}
+
+/// Writes the `USING` keyword of a subquery source.
+pub fn merge_using_query<Sql, Arg>(sql: &mut Sql)
+where
+    Sql: WriteSql<Arg>,
+{
+    separator_optional(sql);
+    sql.push_cmd("USING");
+}
+
+/// Writes the `ON` keyword of the join condition, the condition must be
+/// written afterwards.
+pub fn merge_on<Sql, Arg>(sql: &mut Sql)
+where
+    Sql: WriteSql<Arg>,
+{
+    separator_optional(sql);
+    sql.push_cmd("ON");
+}
+
+/// Writes the `merge_action()` function, returning the action applied to the
+/// row in a `RETURNING` clause.
+pub fn merge_action<Sql, Arg>(sql: &mut Sql)
+where
+    Sql: WriteSql<Arg>,
+{
+    separator_optional(sql);
+    sql.push_cmd("merge_action()");
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        expr::{lhs_binary_rhs, returning, set_column, set_tuple, values, Cmp, Group},
+        sqlexpr,
+        test::TestArgs,
+        SqlCommand,
+    };
+
+    #[test]
+    fn merge_branches() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        merge_into(&mut sql, "stock", Some("s"));
+        merge_using(&mut sql, "delivery", Some("d"));
+        merge_on(&mut sql);
+        sql.push_cmd(" s.item_id = d.item_id");
+
+        let mut when = when_matched(&mut sql);
+        when.and();
+        lhs_binary_rhs(
+            &mut when,
+            sqlexpr::<i32>("s.qty + d.qty"),
+            Cmp::Eq,
+            sqlexpr::<i32>("0"),
+        )
+        .unwrap();
+        when.then_delete();
+        when.close();
+
+        let mut when = when_matched(&mut sql);
+        when.then_update();
+        set_column(&mut when, "qty");
+        when.push_cmd(" s.qty + d.qty,");
+        set_tuple(&mut when, ["updated", "source"]);
+        when.push_cmd(" (now(), ");
+        when.push_value("delivery").unwrap();
+        when.push_cmd(")");
+        when.close();
+
+        let mut when = when_not_matched(&mut sql);
+        when.and();
+        lhs_binary_rhs(
+            &mut when,
+            sqlexpr::<i32>("d.qty"),
+            Cmp::Gt,
+            sqlexpr::<i32>("0"),
+        )
+        .unwrap();
+        when.then_insert(["item_id", "qty"]);
+        values(&mut when);
+        let mut group = Group::open(when.sql());
+        group.push_cmd("d.item_id, d.qty");
+        group.close();
+        when.close();
+
+        let mut when = when_not_matched_by_source(&mut sql);
+        when.and();
+        when.push_cmd(" s.qty = 0");
+        when.close();
+
+        returning(&mut sql);
+        merge_action(&mut sql);
+        sql.push_cmd(", s.*");
+
+        assert_eq!(
+            sql.as_command(),
+            "MERGE INTO stock AS s USING delivery AS d ON s.item_id = d.item_id \
+            WHEN MATCHED AND s.qty + d.qty = 0 THEN DELETE \
+            WHEN MATCHED THEN UPDATE SET qty = s.qty + d.qty, (updated, source) = (now(), $1) \
+            WHEN NOT MATCHED AND d.qty > 0 THEN INSERT (item_id, qty) VALUES (d.item_id, d.qty) \
+            WHEN NOT MATCHED BY SOURCE AND s.qty = 0 THEN DO NOTHING \
+            RETURNING merge_action(), s.*"
+        );
+        assert_eq!(sql.arguments.as_str(), "delivery;");
+    }
+
+    #[test]
+    fn merge_subquery_source() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        merge_into(&mut sql, "account", None);
+        merge_using_query(&mut sql);
+        let mut group = Group::open(&mut sql);
+        group.push_cmd("SELECT id, balance FROM staging WHERE batch = ");
+        group.push_value(7).unwrap();
+        group.close();
+        sql.push_cmd(" AS src");
+        merge_on(&mut sql);
+        sql.push_cmd(" account.id = src.id");
+
+        let mut when = when_not_matched(&mut sql);
+        when.then_insert_default();
+        when.close();
+
+        assert_eq!(
+            sql.as_command(),
+            "MERGE INTO account USING (SELECT id, balance FROM staging WHERE batch = $1) AS src \
+            ON account.id = src.id WHEN NOT MATCHED THEN INSERT DEFAULT VALUES"
+        );
+    }
+}
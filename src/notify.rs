@@ -0,0 +1,162 @@
+//! PostgreSQL `LISTEN` and `NOTIFY` commands.
+//!
+//! Channel names are identifiers, they are always quoted and so case
+//! sensitive: `listen(sql, "Cache")` and `notify(sql, "cache", "")` use
+//! different channels.
+
+use crate::{
+    expr::{quote_identifier, quote_literal, separator_optional},
+    ArgumentBuffer, WriteSql,
+};
+
+/// Channels of a `UNLISTEN` command
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Unlisten<'c> {
+    Channel(&'c str),
+    /// Every channel of the session: `UNLISTEN *`
+    All,
+}
+
+/// Writes a `LISTEN` command.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void};
+/// # use sqlstr::notify::listen;
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// listen(&mut sql, "user_cache");
+///
+/// assert_eq!(sql.as_command(), "LISTEN \"user_cache\"");
+/// ```
+pub fn listen<Sql, Arg>(sql: &mut Sql, channel: &str)
+where
+    Sql: WriteSql<Arg>,
+{
+    separator_optional(sql);
+    sql.push_cmd("LISTEN ");
+    quote_identifier(sql, channel);
+}
+
+/// Writes a `UNLISTEN` command.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void};
+/// # use sqlstr::notify::{unlisten, Unlisten};
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// unlisten(&mut sql, Unlisten::All);
+///
+/// assert_eq!(sql.as_command(), "UNLISTEN *");
+/// ```
+pub fn unlisten<Sql, Arg>(sql: &mut Sql, channel: Unlisten<'_>)
+where
+    Sql: WriteSql<Arg>,
+{
+    separator_optional(sql);
+    sql.push_cmd("UNLISTEN ");
+    match channel {
+        Unlisten::Channel(channel) => quote_identifier(sql, channel),
+        Unlisten::All => sql.push_cmd("*"),
+    }
+}
+
+/// Writes a `NOTIFY` command, without a payload when it is empty.
+///
+/// `NOTIFY` does not accept parameters, the payload is written as a string
+/// literal. Use [pg_notify] to bind the channel and the payload as arguments.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void};
+/// # use sqlstr::notify::notify;
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// notify(&mut sql, "user_cache", "{\"id\": 1, \"name\": \"O'Brien\"}");
+///
+/// assert_eq!(
+///     sql.as_command(),
+///     "NOTIFY \"user_cache\", '{\"id\": 1, \"name\": \"O''Brien\"}'"
+/// );
+/// ```
+pub fn notify<Sql, Arg>(sql: &mut Sql, channel: &str, payload: &str)
+where
+    Sql: WriteSql<Arg>,
+{
+    separator_optional(sql);
+    sql.push_cmd("NOTIFY ");
+    quote_identifier(sql, channel);
+    if !payload.is_empty() {
+        sql.push_cmd(", ");
+        quote_literal(sql, payload);
+    }
+}
+
+/// Writes a `pg_notify($1, $2)` function call, with the channel and the
+/// payload as arguments.
+///
+/// The channel is a text argument matching the quoted channel of [listen],
+/// so both take the same name.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void};
+/// # use sqlstr::expr::select;
+/// # use sqlstr::notify::pg_notify;
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// select(&mut sql);
+/// pg_notify(&mut sql, "user_cache", "1")?;
+///
+/// assert_eq!(sql.as_command(), "SELECT pg_notify($1, $2)");
+/// # Ok(())
+/// # }
+/// ```
+pub fn pg_notify<Sql, Arg, T>(sql: &mut Sql, channel: T, payload: T) -> Result<(), Arg::Error>
+where
+    Sql: WriteSql<Arg>,
+    Arg: ArgumentBuffer<T>,
+{
+    separator_optional(sql);
+    sql.push_cmd("pg_notify(");
+    sql.push_value(channel)?;
+    sql.push_cmd(", ");
+    sql.push_value(payload)?;
+    sql.push_cmd(")");
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::TestArgs, SqlCommand};
+
+    #[test]
+    fn listen_notify() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        listen(&mut sql, "Cache \"v2\"");
+        sql.push_cmd(";");
+        notify(&mut sql, "Cache \"v2\"", "");
+        sql.push_cmd(";");
+        unlisten(&mut sql, Unlisten::Channel("Cache \"v2\""));
+
+        assert_eq!(
+            sql.as_command(),
+            "LISTEN \"Cache \"\"v2\"\"\"; NOTIFY \"Cache \"\"v2\"\"\"; UNLISTEN \"Cache \"\"v2\"\"\""
+        );
+    }
+
+    #[test]
+    fn pg_notify_arguments() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        sql.push_cmd("SELECT");
+        pg_notify(&mut sql, "user_cache", "it's").unwrap();
+
+        assert_eq!(sql.as_command(), "SELECT pg_notify($1, $2)");
+        assert_eq!(sql.arguments.as_str(), "user_cache;it's;");
+    }
+}
@@ -0,0 +1,202 @@
+use core::ops::Range;
+
+use crate::Dialect;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum State {
+    Command,
+    /// Quoted literal or identifier, with backslash escapes
+    Quote(u8, bool),
+    /// Postgres dollar-quoted string, with the range of the `$tag$` delimiter
+    DollarQuote(usize, usize),
+    LineComment,
+    /// Block comment, with the nesting depth
+    BlockComment(u32),
+}
+
+/// Iterator over the `$N` placeholders of a command, with their position in
+/// the command, skipping quoted literals and identifiers and comments.
+///
+/// Backslash escapes in quotes are skipped in MySQL and in Postgres `E'...'`
+/// strings, dollar-quoted strings are skipped in Postgres.
+pub struct Placeholders<'c> {
+    command: &'c str,
+    dialect: Dialect,
+    state: State,
+    index: usize,
+}
+
+impl<'c> Placeholders<'c> {
+    pub const fn new(command: &'c str, dialect: Dialect) -> Self {
+        Self {
+            command,
+            dialect,
+            state: State::Command,
+            index: 0,
+        }
+    }
+
+    /// Returns `true` if the byte before `index` is part of an identifier.
+    fn after_identifier(&self, index: usize) -> bool {
+        index > 0 && is_identifier_byte(self.command.as_bytes()[index - 1])
+    }
+
+    /// Returns the end of a `$tag$` delimiter starting at `start`.
+    fn dollar_tag_end(&self, start: usize) -> Option<usize> {
+        let bytes = self.command.as_bytes();
+        let mut end = start + 1;
+        while end < bytes.len() && is_identifier_byte(bytes[end]) && bytes[end] != b'$' {
+            end += 1;
+        }
+        (end < bytes.len() && bytes[end] == b'$').then_some(end + 1)
+    }
+}
+
+fn is_identifier_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'$' || !byte.is_ascii()
+}
+
+impl<'c> Iterator for Placeholders<'c> {
+    type Item = (Range<usize>, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.command.as_bytes();
+        let nested_comments = matches!(self.dialect, Dialect::Postgres | Dialect::MsSql);
+
+        while self.index < bytes.len() {
+            let start = self.index;
+            let byte = bytes[start];
+            let next = bytes.get(start + 1).copied();
+            self.index += 1;
+
+            match self.state {
+                State::Quote(quote, escapes) => {
+                    if escapes && byte == b'\\' {
+                        self.index += 1;
+                    } else if byte == quote {
+                        self.state = State::Command;
+                    }
+                }
+                State::DollarQuote(tag_start, tag_end) => {
+                    let tag = &self.command[tag_start..tag_end];
+                    if self.command[start..].starts_with(tag) {
+                        self.index = start + tag.len();
+                        self.state = State::Command;
+                    }
+                }
+                State::LineComment => {
+                    if byte == b'\n' {
+                        self.state = State::Command;
+                    }
+                }
+                State::BlockComment(depth) => match (byte, next) {
+                    (b'*', Some(b'/')) => {
+                        self.index += 1;
+                        self.state = match depth {
+                            0 => State::Command,
+                            depth => State::BlockComment(depth - 1),
+                        };
+                    }
+                    (b'/', Some(b'*')) if nested_comments => {
+                        self.index += 1;
+                        self.state = State::BlockComment(depth + 1);
+                    }
+                    _ => {}
+                },
+                State::Command => match byte {
+                    b'\'' => {
+                        let escape_string = self.dialect == Dialect::Postgres
+                            && start > 0
+                            && bytes[start - 1].eq_ignore_ascii_case(&b'e')
+                            && !self.after_identifier(start - 1);
+                        let escapes = self.dialect == Dialect::MySql || escape_string;
+                        self.state = State::Quote(byte, escapes);
+                    }
+                    b'"' | b'`' => {
+                        self.state = State::Quote(byte, self.dialect == Dialect::MySql);
+                    }
+                    b'-' if next == Some(b'-') => {
+                        let mysql_space =
+                            bytes.get(start + 2).map_or(true, u8::is_ascii_whitespace);
+                        if self.dialect != Dialect::MySql || mysql_space {
+                            self.state = State::LineComment;
+                        }
+                    }
+                    b'#' if self.dialect == Dialect::MySql => self.state = State::LineComment,
+                    b'/' if next == Some(b'*') => {
+                        self.index += 1;
+                        self.state = State::BlockComment(0);
+                    }
+                    b'$' if next.is_some_and(|next| next.is_ascii_digit())
+                        && !self.after_identifier(start) =>
+                    {
+                        while self.index < bytes.len() && bytes[self.index].is_ascii_digit() {
+                            self.index += 1;
+                        }
+                        if let Ok(n) = self.command[start + 1..self.index].parse::<u32>() {
+                            return Some((start..self.index, n));
+                        }
+                    }
+                    b'$' if self.dialect == Dialect::Postgres && !self.after_identifier(start) => {
+                        if let Some(end) = self.dollar_tag_end(start) {
+                            self.index = end;
+                            self.state = State::DollarQuote(start, end);
+                        }
+                    }
+                    _ => {}
+                },
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn numbers(command: &str) -> Vec<u32> {
+        Placeholders::new(command, Dialect::Postgres)
+            .map(|(_, n)| n)
+            .collect()
+    }
+
+    #[test]
+    fn placeholders() {
+        assert_eq!(numbers("SELECT 1"), [0; 0]);
+        assert_eq!(numbers("SELECT $2, $10, $1"), [2, 10, 1]);
+        assert_eq!(numbers("SELECT '$5', \"$6\", $1"), [1]);
+        assert_eq!(numbers("SELECT 'it''s $3', $2"), [2]);
+        assert_eq!(numbers("SELECT $, $a"), [0; 0]);
+
+        let command = "a = $12";
+        let (range, n) = Placeholders::new(command, Dialect::Postgres)
+            .next()
+            .unwrap();
+        assert_eq!((&command[range], n), ("$12", 12));
+    }
+
+    #[test]
+    fn skipped_placeholders() {
+        assert_eq!(numbers("SELECT E'\\' $3', $1"), [1]);
+        assert_eq!(numbers("SELECT '\\', $1"), [1]);
+        assert_eq!(numbers("SELECT $$ $3 $$, $tag$ $$ $4 $tag$, $1"), [1]);
+        assert_eq!(numbers("SELECT a$b, $1"), [1]);
+        assert_eq!(numbers("SELECT a$1 FROM t"), [0; 0]);
+        assert_eq!(numbers("SELECT a$1, $2"), [2]);
+        assert_eq!(numbers("SELECT $1 -- $2\n, $3"), [1, 3]);
+        assert_eq!(numbers("SELECT /* $1 /* $2 */ $3 */ $4"), [4]);
+
+        let mysql = |command| -> Vec<u32> {
+            Placeholders::new(command, Dialect::MySql)
+                .map(|(_, n)| n)
+                .collect()
+        };
+        assert_eq!(mysql("SELECT '\\' $3', \"\\\" $4\", $1"), [1]);
+        assert_eq!(mysql("SELECT $1 # $2\n, $3 -- $4"), [1, 3]);
+        assert_eq!(mysql("SELECT $1--$2"), [1, 2]);
+        assert_eq!(mysql("SELECT /* $1 /* */ $2"), [2]);
+    }
+}
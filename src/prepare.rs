@@ -0,0 +1,166 @@
+//! PostgreSQL `PREPARE`, `EXECUTE` and `DEALLOCATE` of built commands.
+//!
+//! Server side prepared statements are needed with connection poolers that
+//! do not keep the protocol level statements of a session.
+
+use alloc::string::String;
+
+use crate::{
+    expr::separator_optional, format_num::format_u32_base10, placeholder::Placeholders, Dialect,
+    SqlCommand, Void, WriteSql,
+};
+
+/// Statements of a `DEALLOCATE` command
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Deallocate<'n> {
+    Statement(&'n str),
+    /// Every prepared statement of the session: `DEALLOCATE ALL`
+    All,
+}
+
+/// Wraps a built command in a `PREPARE <name> [(<types>)] AS <command>`
+/// command.
+///
+/// `PREPARE` does not take arguments, the arguments of the command are passed
+/// to the statement with [execute]. The types are the data types of the `$N`
+/// placeholders in order, they are inferred by the server when not given.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, WriteSql};
+/// # use sqlstr::prepare::{prepare, execute};
+/// # use core::convert::Infallible;
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// sql.push_cmd("SELECT id FROM user WHERE age > ");
+/// sql.push_value(18)?;
+/// sql.push_cmd(" AND name LIKE ");
+/// sql.push_value("a%")?;
+///
+/// let prepared = prepare(&sql, "adult_users", &["int4", "text"]);
+/// assert_eq!(
+///     prepared.as_command(),
+///     "PREPARE adult_users (int4, text) AS SELECT id FROM user WHERE age > $1 AND name LIKE $2"
+/// );
+///
+/// let sql = execute(sql, "adult_users");
+/// assert_eq!(sql.as_command(), "EXECUTE adult_users ($1, $2)");
+/// # Ok(())
+/// # }
+/// ```
+pub fn prepare<Arg>(sql: &SqlCommand<Arg>, name: &str, types: &[&str]) -> SqlCommand<Void> {
+    let mut command = String::with_capacity(sql.command.len() + name.len() + 16);
+    command.push_str("PREPARE ");
+    command.push_str(name);
+
+    if let Some((first, rest)) = types.split_first() {
+        command.push_str(" (");
+        command.push_str(first);
+        for ty in rest {
+            command.push_str(", ");
+            command.push_str(ty);
+        }
+        command.push(')');
+    }
+
+    command.push_str(" AS ");
+    command.push_str(&sql.command);
+
+    SqlCommand::new(command, Void::new())
+}
+
+/// Replaces a built command by the `EXECUTE <name> ($1, ...)` command of its
+/// prepared statement.
+///
+/// The arguments of the command are kept as they are, the `$N` placeholder of
+/// the `EXECUTE` binds the argument of the same placeholder in the command.
+/// The placeholders are counted up to the highest `$N` of the command.
+pub fn execute<Arg>(sql: SqlCommand<Arg>, name: &str) -> SqlCommand<Arg> {
+    let count = Placeholders::new(&sql.command, Dialect::Postgres)
+        .map(|(_, n)| n)
+        .max()
+        .unwrap_or(0);
+
+    let mut command = String::with_capacity(name.len() + 8 + 5 * count as usize);
+    command.push_str("EXECUTE ");
+    command.push_str(name);
+
+    let mut buf = [0; 10];
+    for n in 1..=count {
+        command.push_str(if n == 1 { " ($" } else { ", $" });
+        command.push_str(format_u32_base10(n, &mut buf));
+    }
+    if count > 0 {
+        command.push(')');
+    }
+
+    SqlCommand::new(command, sql.arguments)
+}
+
+/// Writes a `DEALLOCATE` command.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void};
+/// # use sqlstr::prepare::{deallocate, Deallocate};
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// deallocate(&mut sql, Deallocate::Statement("adult_users"));
+///
+/// assert_eq!(sql.as_command(), "DEALLOCATE adult_users");
+/// ```
+pub fn deallocate<Sql, Arg>(sql: &mut Sql, statement: Deallocate<'_>)
+where
+    Sql: WriteSql<Arg>,
+{
+    separator_optional(sql);
+    sql.push_cmd("DEALLOCATE ");
+    match statement {
+        Deallocate::Statement(name) => sql.push_cmd(name),
+        Deallocate::All => sql.push_cmd("ALL"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::TestArgs;
+
+    #[test]
+    fn prepare_execute_arguments() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        sql.push_cmd("UPDATE user SET name = ");
+        sql.push_value("foo").unwrap();
+        sql.push_cmd(" WHERE id = ");
+        sql.push_value(7).unwrap();
+
+        let prepared = prepare(&sql, "rename_user", &[]);
+        assert_eq!(
+            prepared.as_command(),
+            "PREPARE rename_user AS UPDATE user SET name = $1 WHERE id = $2"
+        );
+
+        let sql = execute(sql, "rename_user");
+        assert_eq!(sql.as_command(), "EXECUTE rename_user ($1, $2)");
+        assert_eq!(sql.arguments.as_str(), "foo;7;");
+
+        let sql = execute(SqlCommand::<TestArgs>::default(), "now");
+        assert_eq!(sql.as_command(), "EXECUTE now");
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        sql.push_cmd("SELECT '$3', ");
+        sql.push_value(1).unwrap();
+        let sql = execute(sql, "literal");
+        assert_eq!(sql.as_command(), "EXECUTE literal ($1)");
+    }
+
+    #[test]
+    fn deallocate_all() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        deallocate(&mut sql, Deallocate::All);
+
+        assert_eq!(sql.as_command(), "DEALLOCATE ALL");
+    }
+}
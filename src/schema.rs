@@ -0,0 +1,60 @@
+//! Table definitions.
+//!
+//! With the `derive` feature, [Table] can be derived for a struct with named
+//! fields, generating the table name, the column names and a `<Struct>Column`
+//! enum with a variant for each column. `Insertable` and `AsChangeset`
+//! derive the `INSERT` and `UPDATE` writers of a table struct.
+//!
+//! A [Column] checks the type of the values compared or assigned to it.
+
+mod column;
+
+pub use column::*;
+
+#[cfg(feature = "derive")]
+pub use sqlstr_derive::{AsChangeset, Insertable, Table};
+
+use crate::expr::ColumnExprList;
+
+/// Table with a fixed list of columns
+pub trait Table {
+    /// Name of the table
+    const TABLE: &'static str;
+
+    /// Columns of the table separated by commas, like
+    /// [static_columns](crate::expr::static_columns)
+    const COLUMNS: &'static str;
+
+    /// Column names of the table, in the order of [Table::COLUMNS]
+    const COLUMN_NAMES: &'static [&'static str];
+
+    /// Typed column of the table
+    type Column: Copy;
+
+    /// Returns the list of all the columns of the table.
+    fn column_list() -> ColumnExprList<'static> {
+        Self::COLUMN_NAMES
+            .iter()
+            .fold(ColumnExprList::new(), |list, column| list.column(*column))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Post;
+
+    impl Table for Post {
+        const TABLE: &'static str = "post";
+        const COLUMNS: &'static str = "id, title";
+        const COLUMN_NAMES: &'static [&'static str] = &["id", "title"];
+
+        type Column = ();
+    }
+
+    #[test]
+    fn table_column_list() {
+        assert_eq!(Post::column_list().expr().as_str(), Post::COLUMNS);
+    }
+}
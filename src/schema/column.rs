@@ -0,0 +1,375 @@
+use core::marker::PhantomData;
+
+use crate::{
+    expr::{
+        column,
+        comparison::{is_not_null, is_null},
+        lhs_binary_rhs, set_column, Cmp,
+    },
+    sqlexpr, sqlvalue, ArgumentBuffer, WriteSql,
+};
+
+/// Column of the table `Tbl` with values of type `T`
+///
+/// The comparisons and assignments of the column only accept values of `T`,
+/// so a value of the wrong type is a compile error. `Tbl` is only a label to
+/// tell apart the columns of different tables, the name of the table is not
+/// written. Nullable columns are declared with [NullableColumn], `T` must not
+/// be an `Option`, comparing with a null value is never true.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, WriteSql};
+/// # use sqlstr::expr::{select, from_table, filter_where};
+/// # use sqlstr::schema::{Column, NullableColumn};
+/// # use core::convert::Infallible;
+/// struct User;
+///
+/// const AGE: Column<User, i32> = Column::new("age");
+/// const EMAIL: NullableColumn<User, &str> = NullableColumn::new("email");
+///
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// select(&mut sql);
+/// sql.push_cmd(" id");
+/// from_table(&mut sql, "user");
+/// filter_where(&mut sql);
+/// AGE.gte(&mut sql, 18)?;
+/// sql.push_cmd(" AND");
+/// EMAIL.is_null(&mut sql);
+///
+/// assert_eq!(sql.as_command(), "SELECT id FROM user WHERE age >= $1 AND email IS NULL");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Values of other types do not compile:
+///
+/// ```compile_fail
+/// # use sqlstr::{SqlCommand, Void};
+/// # use sqlstr::schema::Column;
+/// # struct User;
+/// const AGE: Column<User, i32> = Column::new("age");
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// AGE.eq(&mut sql, "18");
+/// ```
+pub struct Column<Tbl, T> {
+    name: &'static str,
+    _marker: PhantomData<fn() -> (Tbl, T)>,
+}
+
+impl<Tbl, T> Column<Tbl, T> {
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            _marker: PhantomData,
+        }
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Writes a `<column> <op> <value>` comparison.
+    pub fn cmp<Sql, Arg>(&self, sql: &mut Sql, op: Cmp, value: T) -> Result<(), Arg::Error>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+    {
+        lhs_binary_rhs(sql, sqlexpr::<T>(self.name), op, sqlvalue(value))
+    }
+
+    /// Writes a `<column> = <value>` comparison.
+    pub fn eq<Sql, Arg>(&self, sql: &mut Sql, value: T) -> Result<(), Arg::Error>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+    {
+        self.cmp(sql, Cmp::Eq, value)
+    }
+
+    /// Writes a `<column> <> <value>` comparison.
+    pub fn neq<Sql, Arg>(&self, sql: &mut Sql, value: T) -> Result<(), Arg::Error>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+    {
+        self.cmp(sql, Cmp::Neq, value)
+    }
+
+    /// Writes a `<column> > <value>` comparison.
+    pub fn gt<Sql, Arg>(&self, sql: &mut Sql, value: T) -> Result<(), Arg::Error>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+    {
+        self.cmp(sql, Cmp::Gt, value)
+    }
+
+    /// Writes a `<column> >= <value>` comparison.
+    pub fn gte<Sql, Arg>(&self, sql: &mut Sql, value: T) -> Result<(), Arg::Error>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+    {
+        self.cmp(sql, Cmp::Gte, value)
+    }
+
+    /// Writes a `<column> < <value>` comparison.
+    pub fn lt<Sql, Arg>(&self, sql: &mut Sql, value: T) -> Result<(), Arg::Error>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+    {
+        self.cmp(sql, Cmp::Lt, value)
+    }
+
+    /// Writes a `<column> <= <value>` comparison.
+    pub fn lte<Sql, Arg>(&self, sql: &mut Sql, value: T) -> Result<(), Arg::Error>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+    {
+        self.cmp(sql, Cmp::Lte, value)
+    }
+
+    /// Writes a `<column> = <value>` assignment of a `UPDATE` command.
+    pub fn set<Sql, Arg>(&self, sql: &mut Sql, value: T) -> Result<(), Arg::Error>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+    {
+        set_column(sql, self.name);
+        sql.push_cmd(" ");
+        sql.push_value(value)
+    }
+}
+
+/// Nullable column of the table `Tbl` with values of type `T`
+///
+/// The comparisons only accept values of `T`, nulls are matched with
+/// [is_null](Self::is_null) and [is_not_null](Self::is_not_null), and rows
+/// where the column is null never match a comparison. The assignments accept
+/// an `Option<T>`, `None` sets the column to null.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void};
+/// # use sqlstr::expr::{update_table, set_update, filter_where};
+/// # use sqlstr::schema::NullableColumn;
+/// # use core::convert::Infallible;
+/// struct User;
+///
+/// const EMAIL: NullableColumn<User, &str> = NullableColumn::new("email");
+///
+/// # fn main() -> Result<(), Infallible> {
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// update_table(&mut sql, "user");
+/// set_update(&mut sql);
+/// EMAIL.set(&mut sql, None)?;
+/// filter_where(&mut sql);
+/// EMAIL.eq(&mut sql, "foo@bar.com")?;
+///
+/// assert_eq!(sql.as_command(), "UPDATE user SET email = $1 WHERE email = $2");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Null values can not be compared:
+///
+/// ```compile_fail
+/// # use sqlstr::{SqlCommand, Void};
+/// # use sqlstr::schema::NullableColumn;
+/// # struct User;
+/// const EMAIL: NullableColumn<User, &str> = NullableColumn::new("email");
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// EMAIL.eq(&mut sql, None);
+/// ```
+pub struct NullableColumn<Tbl, T>(Column<Tbl, T>);
+
+impl<Tbl, T> NullableColumn<Tbl, T> {
+    pub const fn new(name: &'static str) -> Self {
+        Self(Column::new(name))
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.0.name
+    }
+
+    /// Writes a `<column> <op> <value>` comparison.
+    pub fn cmp<Sql, Arg>(&self, sql: &mut Sql, op: Cmp, value: T) -> Result<(), Arg::Error>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+    {
+        self.0.cmp(sql, op, value)
+    }
+
+    /// Writes a `<column> = <value>` comparison.
+    pub fn eq<Sql, Arg>(&self, sql: &mut Sql, value: T) -> Result<(), Arg::Error>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+    {
+        self.0.eq(sql, value)
+    }
+
+    /// Writes a `<column> <> <value>` comparison, rows where the column is
+    /// null do not match.
+    pub fn neq<Sql, Arg>(&self, sql: &mut Sql, value: T) -> Result<(), Arg::Error>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+    {
+        self.0.neq(sql, value)
+    }
+
+    /// Writes a `<column> > <value>` comparison.
+    pub fn gt<Sql, Arg>(&self, sql: &mut Sql, value: T) -> Result<(), Arg::Error>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+    {
+        self.0.gt(sql, value)
+    }
+
+    /// Writes a `<column> >= <value>` comparison.
+    pub fn gte<Sql, Arg>(&self, sql: &mut Sql, value: T) -> Result<(), Arg::Error>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+    {
+        self.0.gte(sql, value)
+    }
+
+    /// Writes a `<column> < <value>` comparison.
+    pub fn lt<Sql, Arg>(&self, sql: &mut Sql, value: T) -> Result<(), Arg::Error>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+    {
+        self.0.lt(sql, value)
+    }
+
+    /// Writes a `<column> <= <value>` comparison.
+    pub fn lte<Sql, Arg>(&self, sql: &mut Sql, value: T) -> Result<(), Arg::Error>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<T>,
+    {
+        self.0.lte(sql, value)
+    }
+
+    /// Writes a `<column> = <value>` assignment of a `UPDATE` command, `None`
+    /// sets the column to null.
+    pub fn set<Sql, Arg>(&self, sql: &mut Sql, value: Option<T>) -> Result<(), Arg::Error>
+    where
+        Sql: WriteSql<Arg>,
+        Arg: ArgumentBuffer<Option<T>>,
+    {
+        set_column(sql, self.0.name);
+        sql.push_cmd(" ");
+        sql.push_value(value)
+    }
+
+    /// Writes a `<column> IS NULL` condition.
+    pub fn is_null<Sql, Arg>(&self, sql: &mut Sql)
+    where
+        Sql: WriteSql<Arg>,
+    {
+        column(sql, self.0.name);
+        is_null(sql);
+    }
+
+    /// Writes a `<column> IS NOT NULL` condition.
+    pub fn is_not_null<Sql, Arg>(&self, sql: &mut Sql)
+    where
+        Sql: WriteSql<Arg>,
+    {
+        column(sql, self.0.name);
+        is_not_null(sql);
+    }
+}
+
+impl<Tbl, T> Clone for Column<Tbl, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Tbl, T> Copy for Column<Tbl, T> {}
+
+impl<Tbl, T> Clone for NullableColumn<Tbl, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Tbl, T> Copy for NullableColumn<Tbl, T> {}
+
+#[cfg(any(feature = "fmt", test, debug_assertions))]
+impl<Tbl, T> core::fmt::Debug for Column<Tbl, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Column").field(&self.name).finish()
+    }
+}
+
+#[cfg(any(feature = "fmt", test, debug_assertions))]
+impl<Tbl, T> core::fmt::Debug for NullableColumn<Tbl, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("NullableColumn").field(&self.0.name).finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        expr::{filter_where, set_update, update_table},
+        test::TestArgs,
+        SqlCommand, Void,
+    };
+
+    struct Account;
+
+    const ID: Column<Account, i64> = Column::new("id");
+    const BALANCE: Column<Account, i64> = Column::new("balance");
+    const NOTE: NullableColumn<Account, &str> = NullableColumn::new("note");
+
+    #[test]
+    fn typed_update() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        update_table(&mut sql, "account");
+        set_update(&mut sql);
+        BALANCE.set(&mut sql, 100).unwrap();
+        filter_where(&mut sql);
+        ID.eq(&mut sql, 7).unwrap();
+        sql.push_cmd(" AND");
+        BALANCE.lt(&mut sql, 50).unwrap();
+        sql.push_cmd(" AND");
+        NOTE.is_not_null(&mut sql);
+
+        assert_eq!(
+            sql.as_command(),
+            "UPDATE account SET balance = $1 WHERE id = $2 AND balance < $3 AND note IS NOT NULL"
+        );
+        assert_eq!(sql.arguments.as_str(), "100;7;50;");
+    }
+
+    #[test]
+    fn nullable_values() {
+        let mut sql: SqlCommand<Void> = SqlCommand::default();
+        NOTE.set(&mut sql, Some("deposit")).unwrap();
+        sql.push_cmd(",");
+        NOTE.set(&mut sql, None).unwrap();
+
+        sql.push_cmd(" WHERE");
+        NOTE.neq(&mut sql, "fee").unwrap();
+
+        assert_eq!(sql.as_command(), "note = $1, note = $2 WHERE note <> $3");
+    }
+}
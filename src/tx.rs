@@ -0,0 +1,559 @@
+//! Transaction control commands.
+
+use alloc::string::String;
+
+use crate::{
+    expr::separator_optional, format_num::format_u32_base10, Dialect, DialectError, WriteSql,
+};
+
+/// Error while writing a transaction control command.
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TxError {
+    Dialect(DialectError),
+    /// There is no savepoint to release or rollback to.
+    NoSavepoint,
+    /// `SET TRANSACTION` without any transaction characteristic.
+    EmptyMode,
+}
+
+#[cfg(feature = "fmt")]
+impl core::fmt::Display for TxError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Dialect(err) => err.fmt(f),
+            Self::NoSavepoint => f.write_str("there is no savepoint"),
+            Self::EmptyMode => f.write_str("SET TRANSACTION needs a transaction characteristic"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TxError {}
+
+impl From<DialectError> for TxError {
+    fn from(value: DialectError) -> Self {
+        Self::Dialect(value)
+    }
+}
+
+/// Transaction isolation level
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+impl IsolationLevel {
+    pub const fn as_str(&self) -> &'static str {
+        match *self {
+            Self::ReadUncommitted => "READ UNCOMMITTED",
+            Self::ReadCommitted => "READ COMMITTED",
+            Self::RepeatableRead => "REPEATABLE READ",
+            Self::Serializable => "SERIALIZABLE",
+        }
+    }
+}
+
+/// Transaction access mode
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum AccessMode {
+    ReadWrite,
+    ReadOnly,
+}
+
+impl AccessMode {
+    pub const fn as_str(&self) -> &'static str {
+        match *self {
+            Self::ReadWrite => "READ WRITE",
+            Self::ReadOnly => "READ ONLY",
+        }
+    }
+}
+
+/// Characteristics of a transaction
+///
+/// Used by [begin] and [set_transaction], unset characteristics use the
+/// database defaults.
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransactionMode {
+    pub isolation: Option<IsolationLevel>,
+    pub access: Option<AccessMode>,
+    pub deferrable: Option<bool>,
+}
+
+impl TransactionMode {
+    pub const fn new() -> Self {
+        Self {
+            isolation: None,
+            access: None,
+            deferrable: None,
+        }
+    }
+
+    pub const fn isolation(mut self, level: IsolationLevel) -> Self {
+        self.isolation = Some(level);
+        self
+    }
+
+    pub const fn access(mut self, mode: AccessMode) -> Self {
+        self.access = Some(mode);
+        self
+    }
+
+    /// `DEFERRABLE` or `NOT DEFERRABLE`, only available in PostgreSQL.
+    pub const fn deferrable(mut self, deferrable: bool) -> Self {
+        self.deferrable = Some(deferrable);
+        self
+    }
+
+    const fn is_empty(&self) -> bool {
+        self.isolation.is_none() && self.access.is_none() && self.deferrable.is_none()
+    }
+}
+
+fn transaction_mode<Sql, Arg>(sql: &mut Sql, mode: &TransactionMode)
+where
+    Sql: WriteSql<Arg>,
+{
+    let mut first = true;
+    let mut item = |sql: &mut Sql| {
+        sql.push_cmd(if first { " " } else { ", " });
+        first = false;
+    };
+
+    if let Some(level) = mode.isolation {
+        item(sql);
+        sql.push_cmd("ISOLATION LEVEL ");
+        sql.push_cmd(level.as_str());
+    }
+    if let Some(access) = mode.access {
+        item(sql);
+        sql.push_cmd(access.as_str());
+    }
+    if let Some(deferrable) = mode.deferrable {
+        item(sql);
+        sql.push_cmd(if deferrable {
+            "DEFERRABLE"
+        } else {
+            "NOT DEFERRABLE"
+        });
+    }
+}
+
+/// Writes the command starting a transaction in the dialect.
+///
+/// MySQL and MSSQL do not accept the isolation level when starting the
+/// transaction, write it with [set_transaction] before.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect};
+/// # use sqlstr::tx::{begin, TransactionMode, IsolationLevel, AccessMode, TxError};
+/// # fn main() -> Result<(), TxError> {
+/// let mode = TransactionMode::new()
+///     .isolation(IsolationLevel::Serializable)
+///     .access(AccessMode::ReadOnly)
+///     .deferrable(true);
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// begin(&mut sql, Dialect::Postgres, &mode)?;
+/// assert_eq!(sql.as_command(), "BEGIN ISOLATION LEVEL SERIALIZABLE, READ ONLY, DEFERRABLE");
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// begin(&mut sql, Dialect::MySql, &TransactionMode::new().access(AccessMode::ReadOnly))?;
+/// assert_eq!(sql.as_command(), "START TRANSACTION READ ONLY");
+/// # Ok(())
+/// # }
+/// ```
+pub fn begin<Sql, Arg>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    mode: &TransactionMode,
+) -> Result<(), TxError>
+where
+    Sql: WriteSql<Arg>,
+{
+    match dialect {
+        Dialect::Postgres => {
+            separator_optional(sql);
+            sql.push_cmd("BEGIN");
+            transaction_mode(sql, mode);
+        }
+        Dialect::MySql => {
+            if mode.isolation.is_some() {
+                return Err(
+                    DialectError::Unsupported("isolation level in START TRANSACTION").into(),
+                );
+            }
+            if mode.deferrable.is_some() {
+                return Err(DialectError::Unsupported("DEFERRABLE").into());
+            }
+            separator_optional(sql);
+            sql.push_cmd("START TRANSACTION");
+            transaction_mode(sql, mode);
+        }
+        Dialect::Sqlite | Dialect::MsSql => {
+            if !mode.is_empty() {
+                return Err(DialectError::Unsupported("transaction characteristics").into());
+            }
+            separator_optional(sql);
+            sql.push_cmd(if dialect == Dialect::Sqlite {
+                "BEGIN"
+            } else {
+                "BEGIN TRANSACTION"
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes a `SET TRANSACTION` command in the dialect.
+///
+/// Returns [TxError::EmptyMode], without writing anything, if the mode is
+/// empty.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect};
+/// # use sqlstr::tx::{set_transaction, TransactionMode, IsolationLevel, TxError};
+/// # fn main() -> Result<(), TxError> {
+/// let mode = TransactionMode::new().isolation(IsolationLevel::RepeatableRead);
+///
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// set_transaction(&mut sql, Dialect::MsSql, &mode)?;
+/// assert_eq!(sql.as_command(), "SET TRANSACTION ISOLATION LEVEL REPEATABLE READ");
+/// # Ok(())
+/// # }
+/// ```
+pub fn set_transaction<Sql, Arg>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    mode: &TransactionMode,
+) -> Result<(), TxError>
+where
+    Sql: WriteSql<Arg>,
+{
+    match dialect {
+        Dialect::Postgres => {}
+        Dialect::MySql if mode.deferrable.is_some() => {
+            return Err(DialectError::Unsupported("DEFERRABLE").into())
+        }
+        Dialect::MySql => {}
+        Dialect::MsSql if mode.access.is_some() || mode.deferrable.is_some() => {
+            return Err(DialectError::Unsupported("transaction access mode").into())
+        }
+        Dialect::MsSql => {}
+        Dialect::Sqlite => return Err(DialectError::Unsupported("SET TRANSACTION").into()),
+    }
+    if mode.is_empty() {
+        return Err(TxError::EmptyMode);
+    }
+
+    separator_optional(sql);
+    sql.push_cmd("SET TRANSACTION");
+    transaction_mode(sql, mode);
+
+    Ok(())
+}
+
+/// Writes a `COMMIT` command.
+pub fn commit<Sql, Arg>(sql: &mut Sql)
+where
+    Sql: WriteSql<Arg>,
+{
+    separator_optional(sql);
+    sql.push_cmd("COMMIT");
+}
+
+/// Writes a `ROLLBACK` command.
+pub fn rollback<Sql, Arg>(sql: &mut Sql)
+where
+    Sql: WriteSql<Arg>,
+{
+    separator_optional(sql);
+    sql.push_cmd("ROLLBACK");
+}
+
+/// Writes the command creating a savepoint in the dialect.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect};
+/// # use sqlstr::tx::savepoint;
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// savepoint(&mut sql, Dialect::MsSql, "before_import");
+/// assert_eq!(sql.as_command(), "SAVE TRANSACTION before_import");
+/// ```
+pub fn savepoint<Sql, Arg>(sql: &mut Sql, dialect: Dialect, name: &str)
+where
+    Sql: WriteSql<Arg>,
+{
+    separator_optional(sql);
+    sql.push_cmd(if dialect == Dialect::MsSql {
+        "SAVE TRANSACTION "
+    } else {
+        "SAVEPOINT "
+    });
+    sql.push_cmd(name);
+}
+
+/// Writes a `RELEASE SAVEPOINT` command.
+///
+/// MSSQL has no command to release a savepoint.
+pub fn release_savepoint<Sql, Arg>(
+    sql: &mut Sql,
+    dialect: Dialect,
+    name: &str,
+) -> Result<(), TxError>
+where
+    Sql: WriteSql<Arg>,
+{
+    if dialect == Dialect::MsSql {
+        return Err(DialectError::Unsupported("RELEASE SAVEPOINT").into());
+    }
+
+    separator_optional(sql);
+    sql.push_cmd("RELEASE SAVEPOINT ");
+    sql.push_cmd(name);
+
+    Ok(())
+}
+
+/// Writes the command rolling back to a savepoint in the dialect.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect};
+/// # use sqlstr::tx::rollback_to;
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+/// rollback_to(&mut sql, Dialect::Postgres, "before_import");
+/// assert_eq!(sql.as_command(), "ROLLBACK TO SAVEPOINT before_import");
+/// ```
+pub fn rollback_to<Sql, Arg>(sql: &mut Sql, dialect: Dialect, name: &str)
+where
+    Sql: WriteSql<Arg>,
+{
+    separator_optional(sql);
+    sql.push_cmd(if dialect == Dialect::MsSql {
+        "ROLLBACK TRANSACTION "
+    } else {
+        "ROLLBACK TO SAVEPOINT "
+    });
+    sql.push_cmd(name);
+}
+
+/// Generator of nested savepoint names
+///
+/// Each nesting level has its own savepoint, named with the prefix followed
+/// by the depth of the level, starting at 1.
+///
+/// # Example
+///
+/// ```
+/// # use sqlstr::{SqlCommand, Void, Dialect};
+/// # use sqlstr::tx::{SavepointStack, TxError};
+/// # fn main() -> Result<(), TxError> {
+/// let mut savepoints = SavepointStack::new("sp_");
+/// let mut sql: SqlCommand<Void> = SqlCommand::default();
+///
+/// savepoints.push(&mut sql, Dialect::Postgres);
+/// sql.push_cmd(";");
+/// savepoints.push(&mut sql, Dialect::Postgres);
+/// sql.push_cmd(";");
+/// savepoints.rollback_to(&mut sql, Dialect::Postgres)?;
+/// sql.push_cmd(";");
+/// savepoints.release(&mut sql, Dialect::Postgres)?;
+/// sql.push_cmd(";");
+/// savepoints.release(&mut sql, Dialect::Postgres)?;
+///
+/// assert_eq!(
+///     sql.as_command(),
+///     "SAVEPOINT sp_1; SAVEPOINT sp_2; ROLLBACK TO SAVEPOINT sp_2; \
+///     RELEASE SAVEPOINT sp_2; RELEASE SAVEPOINT sp_1"
+/// );
+/// assert_eq!(savepoints.depth(), 0);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(any(feature = "fmt", test, debug_assertions), derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct SavepointStack<'p> {
+    prefix: &'p str,
+    depth: u32,
+}
+
+impl<'p> SavepointStack<'p> {
+    pub const fn new(prefix: &'p str) -> Self {
+        Self { prefix, depth: 0 }
+    }
+
+    /// Number of savepoints currently created.
+    pub const fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    /// Name of the innermost savepoint.
+    pub fn current(&self) -> Option<String> {
+        if self.depth == 0 {
+            return None;
+        }
+
+        let mut buf = [0; 10];
+        let mut name = String::with_capacity(self.prefix.len() + 2);
+        name.push_str(self.prefix);
+        name.push_str(format_u32_base10(self.depth, &mut buf));
+        Some(name)
+    }
+
+    /// Creates a savepoint nested in the current one.
+    pub fn push<Sql, Arg>(&mut self, sql: &mut Sql, dialect: Dialect)
+    where
+        Sql: WriteSql<Arg>,
+    {
+        self.depth += 1;
+        if let Some(name) = self.current() {
+            savepoint(sql, dialect, &name);
+        }
+    }
+
+    /// Releases the innermost savepoint.
+    pub fn release<Sql, Arg>(&mut self, sql: &mut Sql, dialect: Dialect) -> Result<(), TxError>
+    where
+        Sql: WriteSql<Arg>,
+    {
+        let name = self.current().ok_or(TxError::NoSavepoint)?;
+        release_savepoint(sql, dialect, &name)?;
+        self.depth -= 1;
+        Ok(())
+    }
+
+    /// Rolls back to the innermost savepoint, which stays available to retry.
+    pub fn rollback_to<Sql, Arg>(&mut self, sql: &mut Sql, dialect: Dialect) -> Result<(), TxError>
+    where
+        Sql: WriteSql<Arg>,
+    {
+        let name = self.current().ok_or(TxError::NoSavepoint)?;
+        rollback_to(sql, dialect, &name);
+        Ok(())
+    }
+
+    /// Forgets the innermost savepoint without writing a command, like after
+    /// a rollback in MSSQL where savepoints are not released.
+    pub fn pop(&mut self) -> Option<String> {
+        let name = self.current();
+        self.depth = self.depth.saturating_sub(1);
+        name
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test::TestArgs, SqlCommand};
+
+    #[test]
+    fn begin_unsupported() {
+        let serializable = TransactionMode::new().isolation(IsolationLevel::Serializable);
+
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        assert_eq!(
+            begin(&mut sql, Dialect::MySql, &serializable),
+            Err(TxError::Dialect(DialectError::Unsupported(
+                "isolation level in START TRANSACTION"
+            )))
+        );
+        assert_eq!(
+            begin(&mut sql, Dialect::Sqlite, &serializable),
+            Err(TxError::Dialect(DialectError::Unsupported(
+                "transaction characteristics"
+            )))
+        );
+        assert_eq!(sql.as_command(), "");
+
+        set_transaction(&mut sql, Dialect::MySql, &serializable).unwrap();
+        sql.push_cmd(";");
+        begin(&mut sql, Dialect::MySql, &TransactionMode::default()).unwrap();
+        assert_eq!(
+            sql.as_command(),
+            "SET TRANSACTION ISOLATION LEVEL SERIALIZABLE; START TRANSACTION"
+        );
+    }
+
+    #[test]
+    fn transaction_commands() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        begin(&mut sql, Dialect::Sqlite, &TransactionMode::new()).unwrap();
+        sql.push_cmd(";");
+        set_transaction(
+            &mut sql,
+            Dialect::Postgres,
+            &TransactionMode::new()
+                .access(AccessMode::ReadWrite)
+                .deferrable(false),
+        )
+        .unwrap();
+        sql.push_cmd(";");
+        commit(&mut sql);
+        sql.push_cmd(";");
+        rollback(&mut sql);
+
+        assert_eq!(
+            sql.as_command(),
+            "BEGIN; SET TRANSACTION READ WRITE, NOT DEFERRABLE; COMMIT; ROLLBACK"
+        );
+    }
+
+    #[test]
+    fn set_transaction_empty() {
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+        for dialect in [Dialect::Postgres, Dialect::MySql, Dialect::MsSql] {
+            assert_eq!(
+                set_transaction(&mut sql, dialect, &TransactionMode::new()),
+                Err(TxError::EmptyMode)
+            );
+        }
+        assert_eq!(sql.as_command(), "");
+    }
+
+    #[test]
+    fn savepoint_stack_mssql() {
+        let mut savepoints = SavepointStack::new("retry");
+        let mut sql: SqlCommand<TestArgs> = SqlCommand::default();
+
+        assert_eq!(
+            savepoints.rollback_to(&mut sql, Dialect::MsSql),
+            Err(TxError::NoSavepoint)
+        );
+
+        begin(&mut sql, Dialect::MsSql, &TransactionMode::new()).unwrap();
+        sql.push_cmd(";");
+        savepoints.push(&mut sql, Dialect::MsSql);
+        sql.push_cmd(";");
+        savepoints.rollback_to(&mut sql, Dialect::MsSql).unwrap();
+
+        assert_eq!(
+            savepoints.release(&mut sql, Dialect::MsSql),
+            Err(TxError::Dialect(DialectError::Unsupported(
+                "RELEASE SAVEPOINT"
+            )))
+        );
+        assert_eq!(savepoints.pop().as_deref(), Some("retry1"));
+        assert_eq!(savepoints.pop(), None);
+
+        assert_eq!(
+            sql.as_command(),
+            "BEGIN TRANSACTION; SAVE TRANSACTION retry1; ROLLBACK TRANSACTION retry1"
+        );
+    }
+}